@@ -23,6 +23,25 @@ pub enum CliPromptError {
     OptionsVecEmptyError { message: String },
     /// Used for [`prompt_multi_select_with_max_choice_num`](crate::CliPrompt::prompt_multi_select_with_max_choice_num)
     InvalidMaxChoiceNumError { message: String },
+    /// Used for [`prompt_multiline_with_max_lines`](crate::CliPrompt::prompt_multiline_with_max_lines)
+    InvalidMaxLinesError { message: String },
+    /// `min` is later than `max`. Used for [`prompt_date_with_bounds`](crate::CliPrompt::prompt_date_with_bounds)
+    InvalidDateRangeError { message: String },
+    /// `min` is not less than `max`, or `step` is not positive. Used for [`prompt_slider_with_step`](crate::CliPrompt::prompt_slider_with_step)
+    InvalidSliderError { message: String },
+    /// `headers` is empty, or a row has a different number of cells. Used for [`prompt_table_select`](crate::CliPrompt::prompt_table_select)
+    InvalidTableError { message: String },
+    /// A hotkey is reserved, not alphanumeric, or used twice. Used for [`prompt_expand`](crate::CliPrompt::prompt_expand)
+    InvalidHotkeyError { message: String },
+    /// Two fields or steps have the same name, or a step chooses an unknown step as the next one.
+    /// Used for [`prompt_form`](crate::CliPrompt::prompt_form), [`prompt_wizard`](crate::CliPrompt::prompt_wizard) and [`prompt_group`](crate::CliPrompt::prompt_group)
+    InvalidFormError { message: String },
+    /// The answers can't be converted into the results. Used for [`prompt_group`](crate::CliPrompt::prompt_group)
+    InvalidAnswersError { message: String },
+    /// The external editor can't be launched or exits with failure. Used for [`prompt_editor`](crate::CliPrompt::prompt_editor)
+    EditorError { message: String },
+    /// Users cancelled the prompt by Escape key. Used for [`prompt_confirm_typed`](crate::CliPrompt::prompt_confirm_typed), [`prompt_wizard`](crate::CliPrompt::prompt_wizard) and [`prompt_group`](crate::CliPrompt::prompt_group)
+    Cancelled,
     /// Indicates an underlying Spinner Error.
     SpinnerError(SpinnerError),
 }
@@ -39,6 +58,15 @@ impl Display for CliPromptError {
             CliPromptError::IoError(io_error) => write!(f, "{}", io_error),
             CliPromptError::OptionsVecEmptyError { message } => write!(f, "{}", message),
             CliPromptError::InvalidMaxChoiceNumError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidMaxLinesError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidDateRangeError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidSliderError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidTableError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidHotkeyError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidFormError { message } => write!(f, "{}", message),
+            CliPromptError::InvalidAnswersError { message } => write!(f, "{}", message),
+            CliPromptError::EditorError { message } => write!(f, "{}", message),
+            CliPromptError::Cancelled => write!(f, "cancelled by user"),
             CliPromptError::SpinnerError(spinner_error) => spinner_error.fmt(f),
         }
     }
@@ -1,6 +1,8 @@
 //! Collection of functions to style text on terminal.
 //! Functions with postfix "_bg" change the background color
 //!
+//! Whether the functions emit colors follows [`colors_enabled`].
+//!
 //! # Example
 //! ```no_run
 //! use cli_prompts_rs::color::*;
@@ -8,78 +10,686 @@
 //! println!("{}", red("message"));
 //! println!("{}", yellow_bg("message"));
 //! ```
-use console::{style, StyledObject};
+use console::Term;
+use std::env;
+use std::fmt::{self, Display};
+
+/// Controls whether styled output contains colors.
+///
+/// Use with [`CliPrompt::set_color_mode`](crate::CliPrompt::set_color_mode) to override the environment for one instance.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ColorMode {
+    /// Follows [`colors_enabled`]
+    #[default]
+    Auto,
+    /// Always prints colors
+    Always,
+    /// Never prints colors
+    Never,
+}
+
+impl ColorMode {
+    /// Returns true if colors should be printed in this mode.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorMode::Auto => colors_enabled(),
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
+
+/// Returns true if colors should be printed to stdout.
+///
+/// The rules are checked in order:
+/// - `CLICOLOR_FORCE` is set and not `0`: colors are enabled
+/// - `NO_COLOR` is set and not empty: colors are disabled
+/// - `CLICOLOR` is `0`: colors are disabled
+/// - otherwise colors are enabled when stdout is a terminal which supports colors
+pub fn colors_enabled() -> bool {
+    colors_enabled_with(
+        |key| env::var(key).ok(),
+        Term::stdout().features().colors_supported(),
+    )
+}
+
+fn colors_enabled_with<F>(var: F, colors_supported: bool) -> bool
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+        return true;
+    }
+
+    if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+
+    if var("CLICOLOR").is_some_and(|value| value == "0") {
+        return false;
+    }
+
+    colors_supported
+}
+
+/// A color used by [`Style`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// A color of the 256 colors palette
+    Color256(u8),
+    /// A 24-bit color, downsampled when the terminal doesn't support it
+    Rgb(Rgb),
+}
+
+impl Color {
+    fn ansi_code(self, depth: ColorDepth, background: bool) -> String {
+        let basic = |index: u8| {
+            let base = if index < 8 { 30 + index } else { 82 + index };
+            format!("{}", if background { base + 10 } else { base })
+        };
+
+        match self {
+            Color::Black => basic(0),
+            Color::Red => basic(1),
+            Color::Green => basic(2),
+            Color::Yellow => basic(3),
+            Color::Blue => basic(4),
+            Color::Magenta => basic(5),
+            Color::Cyan => basic(6),
+            Color::White => basic(7),
+            Color::BrightBlack => basic(8),
+            Color::BrightRed => basic(9),
+            Color::BrightGreen => basic(10),
+            Color::BrightYellow => basic(11),
+            Color::BrightBlue => basic(12),
+            Color::BrightMagenta => basic(13),
+            Color::BrightCyan => basic(14),
+            Color::BrightWhite => basic(15),
+            Color::Color256(index) => format!("{};5;{}", if background { 48 } else { 38 }, index),
+            Color::Rgb(rgb) => rgb.ansi_code(depth, background),
+        }
+    }
+}
+
+/// A composable style which can be stored and applied to any [`Display`].
+///
+/// # Example
+/// ```no_run
+/// use cli_prompts_rs::color::{Color, Rgb, Style};
+///
+/// let warning = Style::new()
+///     .fg(Color::Rgb(Rgb::new(255, 136, 0)))
+///     .bg(Color::Black)
+///     .bold()
+///     .underline();
+/// println!("{}", warning.apply_to("careful"));
+/// println!("{}", warning.apply_to(42));
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+    reverse: bool,
+}
+
+impl Style {
+    /// Returns a style without any color or attribute.
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            strikethrough: false,
+            reverse: false,
+        }
+    }
+
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    pub const fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    pub const fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Returns true if the style has neither color nor attribute.
+    pub fn is_plain(&self) -> bool {
+        *self == Style::new()
+    }
+
+    /// Wraps the value so that it is printed with this style.
+    pub fn apply_to<D>(&self, val: D) -> StyledText<D> {
+        StyledText {
+            val,
+            style: *self,
+            force: None,
+        }
+    }
+
+    /// Returns the style with the colors and attributes of `other` added on top.
+    pub(crate) fn merge(&self, other: &Style) -> Style {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            bold: self.bold || other.bold,
+            dim: self.dim || other.dim,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+            strikethrough: self.strikethrough || other.strikethrough,
+            reverse: self.reverse || other.reverse,
+        }
+    }
+
+    pub(crate) fn sgr(&self, depth: ColorDepth) -> String {
+        let attrs = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.reverse, "7"),
+            (self.strikethrough, "9"),
+        ];
+        let mut codes: Vec<String> = attrs
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, code)| code.to_string())
+            .collect();
+
+        if let Some(fg) = self.fg {
+            codes.push(fg.ansi_code(depth, false));
+        }
+        if let Some(bg) = self.bg {
+            codes.push(bg.ansi_code(depth, true));
+        }
 
-pub fn black<D>(message: D) -> StyledObject<D> {
-    style(message).black()
+        codes.join(";")
+    }
 }
 
-pub fn red<D>(message: D) -> StyledObject<D> {
-    style(message).red()
+/// A value with a [`Style`], returned by [`Style::apply_to`] and the functions of this module.
+///
+/// Whether it is printed with colors follows [`colors_enabled`], unless [`force_styling`](StyledText::force_styling) is called.
+#[derive(Debug, Clone)]
+pub struct StyledText<D> {
+    val: D,
+    style: Style,
+    force: Option<bool>,
 }
 
-pub fn green<D>(message: D) -> StyledObject<D> {
-    style(message).green()
+impl<D> StyledText<D> {
+    /// Forces the styling on or off, regardless of [`colors_enabled`].
+    pub fn force_styling(mut self, value: bool) -> Self {
+        self.force = Some(value);
+        self
+    }
+
+    pub fn fg(mut self, color: Color) -> Self {
+        self.style = self.style.fg(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.style = self.style.bg(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.style = self.style.bold();
+        self
+    }
+
+    pub fn dim(mut self) -> Self {
+        self.style = self.style.dim();
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.style = self.style.italic();
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.style = self.style.underline();
+        self
+    }
+
+    pub fn strikethrough(mut self) -> Self {
+        self.style = self.style.strikethrough();
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.style = self.style.reverse();
+        self
+    }
 }
 
-pub fn yellow<D>(message: D) -> StyledObject<D> {
-    style(message).yellow()
+impl<D: Display> Display for StyledText<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let enabled = self.force.unwrap_or_else(colors_enabled);
+
+        if !enabled || self.style.is_plain() {
+            return self.val.fmt(f);
+        }
+
+        write!(f, "\x1b[{}m", self.style.sgr(color_depth()))?;
+        self.val.fmt(f)?;
+        write!(f, "\x1b[0m")
+    }
 }
 
-pub fn blue<D>(message: D) -> StyledObject<D> {
-    style(message).blue()
+pub fn black<D>(message: D) -> StyledText<D> {
+    Style::new().fg(Color::Black).apply_to(message)
 }
 
-pub fn magenta<D>(message: D) -> StyledObject<D> {
-    style(message).on_magenta()
+pub fn red<D>(message: D) -> StyledText<D> {
+    Style::new().fg(Color::Red).apply_to(message)
 }
 
-pub fn cyan<D>(message: D) -> StyledObject<D> {
-    style(message).on_cyan()
+pub fn green<D>(message: D) -> StyledText<D> {
+    Style::new().fg(Color::Green).apply_to(message)
 }
 
-pub fn white<D>(message: D) -> StyledObject<D> {
-    style(message).on_white()
+pub fn yellow<D>(message: D) -> StyledText<D> {
+    Style::new().fg(Color::Yellow).apply_to(message)
 }
 
-pub fn color256<D>(message: D, color: u8) -> StyledObject<D> {
-    style(message).color256(color)
+pub fn blue<D>(message: D) -> StyledText<D> {
+    Style::new().fg(Color::Blue).apply_to(message)
 }
 
-pub fn black_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_black()
+pub fn magenta<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Magenta).apply_to(message)
 }
 
-pub fn red_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_red()
+pub fn cyan<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Cyan).apply_to(message)
 }
 
-pub fn green_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_green().red().italic()
+pub fn white<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::White).apply_to(message)
 }
 
-pub fn yellow_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_yellow()
+pub fn color256<D>(message: D, color: u8) -> StyledText<D> {
+    Style::new().fg(Color::Color256(color)).apply_to(message)
 }
 
-pub fn blue_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_blue()
+pub fn black_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Black).apply_to(message)
 }
 
-pub fn magenta_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_magenta()
+pub fn red_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Red).apply_to(message)
 }
 
-pub fn cyan_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_cyan()
+pub fn green_bg<D>(message: D) -> StyledText<D> {
+    Style::new()
+        .bg(Color::Green)
+        .fg(Color::Red)
+        .italic()
+        .apply_to(message)
 }
 
-pub fn white_bg<D>(message: D) -> StyledObject<D> {
-    style(message).on_white()
+pub fn yellow_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Yellow).apply_to(message)
 }
 
-pub fn color256_bg<D>(message: D, color: u8) -> StyledObject<D> {
-    style(message).on_color256(color)
+pub fn blue_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Blue).apply_to(message)
+}
+
+pub fn magenta_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Magenta).apply_to(message)
+}
+
+pub fn cyan_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::Cyan).apply_to(message)
+}
+
+pub fn white_bg<D>(message: D) -> StyledText<D> {
+    Style::new().bg(Color::White).apply_to(message)
+}
+
+pub fn color256_bg<D>(message: D, color: u8) -> StyledText<D> {
+    Style::new().bg(Color::Color256(color)).apply_to(message)
+}
+
+/// The number of colors the terminal can print.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ColorDepth {
+    /// The 16 basic colors
+    Ansi16,
+    /// The 256 colors palette
+    Ansi256,
+    /// 24-bit colors
+    TrueColor,
+}
+
+/// Detects the color depth of the terminal from `COLORTERM` and `TERM`.
+pub fn color_depth() -> ColorDepth {
+    color_depth_with(|key| env::var(key).ok())
+}
+
+fn color_depth_with<F>(var: F) -> ColorDepth
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if var("COLORTERM").is_some_and(|value| value == "truecolor" || value == "24bit")
+        || var("WT_SESSION").is_some()
+    {
+        return ColorDepth::TrueColor;
+    }
+
+    if var("TERM").is_some_and(|value| value.contains("256color")) {
+        return ColorDepth::Ansi256;
+    }
+
+    ColorDepth::Ansi16
+}
+
+/// A 24-bit color.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+const ANSI16_PALETTE: [Rgb; 16] = [
+    Rgb::new(0, 0, 0),
+    Rgb::new(205, 0, 0),
+    Rgb::new(0, 205, 0),
+    Rgb::new(205, 205, 0),
+    Rgb::new(0, 0, 238),
+    Rgb::new(205, 0, 205),
+    Rgb::new(0, 205, 205),
+    Rgb::new(229, 229, 229),
+    Rgb::new(127, 127, 127),
+    Rgb::new(255, 0, 0),
+    Rgb::new(0, 255, 0),
+    Rgb::new(255, 255, 0),
+    Rgb::new(92, 92, 255),
+    Rgb::new(255, 0, 255),
+    Rgb::new(0, 255, 255),
+    Rgb::new(255, 255, 255),
+];
+
+const NAMED_COLORS: [(&str, Rgb); 28] = [
+    ("black", Rgb::new(0, 0, 0)),
+    ("white", Rgb::new(255, 255, 255)),
+    ("red", Rgb::new(255, 0, 0)),
+    ("green", Rgb::new(0, 128, 0)),
+    ("blue", Rgb::new(0, 0, 255)),
+    ("yellow", Rgb::new(255, 255, 0)),
+    ("cyan", Rgb::new(0, 255, 255)),
+    ("magenta", Rgb::new(255, 0, 255)),
+    ("gray", Rgb::new(128, 128, 128)),
+    ("grey", Rgb::new(128, 128, 128)),
+    ("silver", Rgb::new(192, 192, 192)),
+    ("maroon", Rgb::new(128, 0, 0)),
+    ("olive", Rgb::new(128, 128, 0)),
+    ("lime", Rgb::new(0, 255, 0)),
+    ("teal", Rgb::new(0, 128, 128)),
+    ("navy", Rgb::new(0, 0, 128)),
+    ("purple", Rgb::new(128, 0, 128)),
+    ("orange", Rgb::new(255, 165, 0)),
+    ("gold", Rgb::new(255, 215, 0)),
+    ("pink", Rgb::new(255, 192, 203)),
+    ("brown", Rgb::new(165, 42, 42)),
+    ("coral", Rgb::new(255, 127, 80)),
+    ("salmon", Rgb::new(250, 128, 114)),
+    ("crimson", Rgb::new(220, 20, 60)),
+    ("indigo", Rgb::new(75, 0, 130)),
+    ("violet", Rgb::new(238, 130, 238)),
+    ("turquoise", Rgb::new(64, 224, 208)),
+    ("skyblue", Rgb::new(135, 206, 235)),
+];
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parses `#rrggbb` or `#rgb`. The leading `#` is optional.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.is_ascii() {
+            return None;
+        }
+
+        match hex.len() {
+            6 => Some(Self::new(
+                u8::from_str_radix(&hex[0..2], 16).ok()?,
+                u8::from_str_radix(&hex[2..4], 16).ok()?,
+                u8::from_str_radix(&hex[4..6], 16).ok()?,
+            )),
+            3 => {
+                let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).map(|v| v * 17);
+                Some(Self::new(digit(0).ok()?, digit(1).ok()?, digit(2).ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Looks up a CSS color name such as `orange` or `skyblue`, case insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(color_name, _)| color_name.eq_ignore_ascii_case(name))
+            .map(|(_, rgb)| *rgb)
+    }
+
+    /// Returns the closest color of the 256 colors palette.
+    pub fn to_ansi256(self) -> u8 {
+        if self.r == self.g && self.g == self.b {
+            return match self.r {
+                0..=7 => 16,
+                248..=255 => 231,
+                v => 232 + ((v - 8 + 5) / 10).min(23),
+            };
+        }
+
+        let to_cube = |v: u8| -> u8 {
+            match v {
+                0..=47 => 0,
+                48..=114 => 1,
+                v => (v - 35) / 40,
+            }
+        };
+
+        16 + 36 * to_cube(self.r) + 6 * to_cube(self.g) + to_cube(self.b)
+    }
+
+    /// Returns the index of the closest color of the 16 basic colors.
+    pub fn to_ansi16(self) -> u8 {
+        let distance = |other: &Rgb| -> u32 {
+            let dr = self.r as i32 - other.r as i32;
+            let dg = self.g as i32 - other.g as i32;
+            let db = self.b as i32 - other.b as i32;
+            (dr * dr + dg * dg + db * db) as u32
+        };
+
+        ANSI16_PALETTE
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, color)| distance(color))
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    }
+
+    fn lerp(self, other: Rgb, t: f32) -> Self {
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+
+    pub(crate) fn ansi_code(self, depth: ColorDepth, background: bool) -> String {
+        match depth {
+            ColorDepth::TrueColor => format!(
+                "{};2;{};{};{}",
+                if background { 48 } else { 38 },
+                self.r,
+                self.g,
+                self.b
+            ),
+            ColorDepth::Ansi256 => format!(
+                "{};5;{}",
+                if background { 48 } else { 38 },
+                self.to_ansi256()
+            ),
+            ColorDepth::Ansi16 => {
+                let index = self.to_ansi16();
+                let base = if index < 8 { 30 + index } else { 82 + index };
+                format!("{}", if background { base + 10 } else { base })
+            }
+        }
+    }
+}
+
+fn paint<D: Display>(message: D, color: Rgb, background: bool, depth: ColorDepth) -> String {
+    format!(
+        "\x1b[{}m{}\x1b[0m",
+        color.ansi_code(depth, background),
+        message
+    )
+}
+
+fn rgb_style(color: Option<Rgb>, background: bool) -> Style {
+    match (color, background) {
+        (Some(color), false) => Style::new().fg(Color::Rgb(color)),
+        (Some(color), true) => Style::new().bg(Color::Rgb(color)),
+        (None, _) => Style::new(),
+    }
+}
+
+/// Colors the message with 24-bit color.
+/// The color is downsampled to the closest one when the terminal doesn't support 24-bit color.
+pub fn rgb<D>(message: D, r: u8, g: u8, b: u8) -> StyledText<D> {
+    rgb_style(Some(Rgb::new(r, g, b)), false).apply_to(message)
+}
+
+pub fn rgb_bg<D>(message: D, r: u8, g: u8, b: u8) -> StyledText<D> {
+    rgb_style(Some(Rgb::new(r, g, b)), true).apply_to(message)
+}
+
+/// Colors the message with hex color like `#ff8800`.
+/// If `hex` is not a valid color, the message is printed without color.
+pub fn hex<D>(message: D, hex: &str) -> StyledText<D> {
+    rgb_style(Rgb::from_hex(hex), false).apply_to(message)
+}
+
+pub fn hex_bg<D>(message: D, hex: &str) -> StyledText<D> {
+    rgb_style(Rgb::from_hex(hex), true).apply_to(message)
+}
+
+/// Colors the message with CSS color name like `orange`.
+/// If `name` is not a known color, the message is printed without color.
+pub fn named<D>(message: D, name: &str) -> StyledText<D> {
+    rgb_style(Rgb::from_name(name), false).apply_to(message)
+}
+
+pub fn named_bg<D>(message: D, name: &str) -> StyledText<D> {
+    rgb_style(Rgb::from_name(name), true).apply_to(message)
+}
+
+/// Colors each character of the message with a gradient going through `stops`.
+///
+/// # Example
+/// ```no_run
+/// use cli_prompts_rs::color::{gradient, Rgb};
+///
+/// println!("{}", gradient("rainbow", &[Rgb::new(255, 0, 0), Rgb::new(0, 0, 255)]));
+/// ```
+pub fn gradient(message: &str, stops: &[Rgb]) -> String {
+    if stops.is_empty() || !colors_enabled() {
+        return message.to_string();
+    }
+
+    gradient_with_depth(message, stops, color_depth())
+}
+
+fn gradient_with_depth(message: &str, stops: &[Rgb], depth: ColorDepth) -> String {
+    let chars: Vec<char> = message.chars().collect();
+    let last = chars.len().saturating_sub(1).max(1) as f32;
+    let segments = (stops.len() - 1) as f32;
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            if c.is_whitespace() {
+                return c.to_string();
+            }
+
+            if stops.len() == 1 {
+                return paint(c, stops[0], false, depth);
+            }
+
+            let position = i as f32 / last * segments;
+            let index = (position.floor() as usize).min(stops.len() - 2);
+            let color = stops[index].lerp(stops[index + 1], position - index as f32);
+            paint(c, color, false, depth)
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -97,4 +707,197 @@ mod tests {
         // uncomment to see result output
         // assert!(false)
     }
+
+    fn env_of<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_color_helpers_keep_background() {
+        assert_eq!(
+            magenta("a").force_styling(true).to_string(),
+            "\x1b[45ma\x1b[0m"
+        );
+        assert_eq!(
+            white("a").force_styling(true).to_string(),
+            "\x1b[47ma\x1b[0m"
+        );
+        assert_eq!(
+            green_bg("a").force_styling(true).to_string(),
+            "\x1b[3;31;42ma\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_colors_enabled_follows_terminal() {
+        assert!(colors_enabled_with(env_of(&[]), true));
+        assert!(!colors_enabled_with(env_of(&[]), false));
+    }
+
+    #[test]
+    fn test_colors_enabled_no_color() {
+        assert!(!colors_enabled_with(env_of(&[("NO_COLOR", "1")]), true));
+        assert!(colors_enabled_with(env_of(&[("NO_COLOR", "")]), true));
+    }
+
+    #[test]
+    fn test_colors_enabled_clicolor() {
+        assert!(!colors_enabled_with(env_of(&[("CLICOLOR", "0")]), true));
+        assert!(colors_enabled_with(env_of(&[("CLICOLOR", "1")]), true));
+        assert!(!colors_enabled_with(env_of(&[("CLICOLOR", "1")]), false));
+    }
+
+    #[test]
+    fn test_colors_enabled_clicolor_force() {
+        assert!(colors_enabled_with(
+            env_of(&[("CLICOLOR_FORCE", "1")]),
+            false
+        ));
+        assert!(colors_enabled_with(
+            env_of(&[
+                ("CLICOLOR_FORCE", "1"),
+                ("NO_COLOR", "1"),
+                ("CLICOLOR", "0")
+            ]),
+            false
+        ));
+        assert!(!colors_enabled_with(
+            env_of(&[("CLICOLOR_FORCE", "0")]),
+            false
+        ));
+    }
+
+    #[test]
+    fn test_color_depth() {
+        assert_eq!(
+            color_depth_with(env_of(&[("COLORTERM", "truecolor")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            color_depth_with(env_of(&[("TERM", "xterm-256color")])),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            color_depth_with(env_of(&[("TERM", "xterm")])),
+            ColorDepth::Ansi16
+        );
+    }
+
+    #[test]
+    fn test_rgb_from_hex() {
+        assert_eq!(Rgb::from_hex("#ff8800"), Some(Rgb::new(255, 136, 0)));
+        assert_eq!(Rgb::from_hex("FF8800"), Some(Rgb::new(255, 136, 0)));
+        assert_eq!(Rgb::from_hex("#f80"), Some(Rgb::new(255, 136, 0)));
+        assert_eq!(Rgb::from_hex("#ff880"), None);
+        assert_eq!(Rgb::from_hex("#gg8800"), None);
+        assert_eq!(Rgb::from_hex("#ff88é"), None);
+    }
+
+    #[test]
+    fn test_rgb_from_name() {
+        assert_eq!(Rgb::from_name("orange"), Some(Rgb::new(255, 165, 0)));
+        assert_eq!(Rgb::from_name("SkyBlue"), Some(Rgb::new(135, 206, 235)));
+        assert_eq!(Rgb::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_rgb_downsample() {
+        assert_eq!(Rgb::new(255, 0, 0).to_ansi256(), 196);
+        assert_eq!(Rgb::new(0, 0, 0).to_ansi256(), 16);
+        assert_eq!(Rgb::new(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(Rgb::new(255, 136, 0).to_ansi256(), 208);
+
+        assert_eq!(Rgb::new(250, 10, 10).to_ansi16(), 9);
+        assert_eq!(Rgb::new(0, 0, 0).to_ansi16(), 0);
+        assert_eq!(Rgb::new(120, 120, 130).to_ansi16(), 8);
+    }
+
+    #[test]
+    fn test_paint_with_depth() {
+        let color = Rgb::new(255, 0, 0);
+
+        assert_eq!(
+            paint("a", color, false, ColorDepth::TrueColor),
+            "\x1b[38;2;255;0;0ma\x1b[0m"
+        );
+        assert_eq!(
+            paint("a", color, true, ColorDepth::Ansi256),
+            "\x1b[48;5;196ma\x1b[0m"
+        );
+        assert_eq!(
+            paint("a", color, false, ColorDepth::Ansi16),
+            "\x1b[91ma\x1b[0m"
+        );
+        assert_eq!(
+            paint("a", Rgb::new(0, 0, 0), true, ColorDepth::Ansi16),
+            "\x1b[40ma\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let result = gradient_with_depth(
+            "ab c",
+            &[Rgb::new(0, 0, 0), Rgb::new(255, 255, 255)],
+            ColorDepth::TrueColor,
+        );
+
+        assert_eq!(
+            result,
+            "\x1b[38;2;0;0;0ma\x1b[0m\x1b[38;2;85;85;85mb\x1b[0m \x1b[38;2;255;255;255mc\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_style() {
+        let style = Style::new()
+            .fg(Color::Red)
+            .bg(Color::BrightBlue)
+            .bold()
+            .dim()
+            .italic()
+            .underline()
+            .strikethrough()
+            .reverse();
+
+        assert_eq!(
+            style.apply_to("a").force_styling(true).to_string(),
+            "\x1b[1;2;3;4;7;9;31;104ma\x1b[0m"
+        );
+        assert_eq!(style.apply_to("a").force_styling(false).to_string(), "a");
+        assert_eq!(
+            Style::new().apply_to("a").force_styling(true).to_string(),
+            "a"
+        );
+        assert_eq!(
+            red(7).bold().force_styling(true).to_string(),
+            "\x1b[1;31m7\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:>3}", green("a").force_styling(true)),
+            "\x1b[32m  a\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_style_merge() {
+        let base = Style::new().fg(Color::Red).bold();
+        let merged = base.merge(&Style::new().fg(Color::Color256(42)).underline());
+
+        assert_eq!(
+            merged,
+            Style::new().fg(Color::Color256(42)).bold().underline()
+        );
+    }
+
+    #[test]
+    fn test_color_mode() {
+        assert!(ColorMode::Always.enabled());
+        assert!(!ColorMode::Never.enabled());
+        assert_eq!(ColorMode::default(), ColorMode::Auto);
+    }
 }
@@ -0,0 +1,107 @@
+//! Builtin completers for [`prompt_text_with_completer`](crate::CliPrompt::prompt_text_with_completer).
+use std::env;
+use std::fs;
+use std::path::{is_separator, PathBuf, MAIN_SEPARATOR_STR};
+
+/// Completes the input as a filesystem path, relative to the current directory.
+///
+/// Returns the entries of the input's directory whose names start with the last component of the input,
+/// sorted by name. Directories end with the path separator, so that the next Tab key continues into them.
+/// Hidden entries are returned only when the last component starts with `.`, and a leading `~` is the home directory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use cli_prompts_rs::completion::path_completer;
+///
+/// // ["src/cli_prompt_error.rs", "src/color.rs", "src/completion.rs"]
+/// let candidates = path_completer("src/c");
+/// ```
+pub fn path_completer(input: &str) -> Vec<String> {
+    let (dir_part, name_prefix) = match input.rfind(is_separator) {
+        Some(index) => input.split_at(index + 1),
+        None => ("", input),
+    };
+    let dir = match dir_part {
+        "" => PathBuf::from("."),
+        _ => expand_home(dir_part),
+    };
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut candidates: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !name.starts_with(name_prefix)
+                || (name.starts_with('.') && !name_prefix.starts_with('.'))
+            {
+                return None;
+            }
+            let separator = if entry.path().is_dir() {
+                MAIN_SEPARATOR_STR
+            } else {
+                ""
+            };
+
+            Some(format!("{}{}{}", dir_part, name, separator))
+        })
+        .collect();
+    candidates.sort();
+
+    candidates
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), env::var_os("HOME")) {
+        (Some(rest), Some(home)) if rest.starts_with(is_separator) => {
+            PathBuf::from(home).join(&rest[1..])
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_completer() {
+        let dir = env::temp_dir().join(format!("cli_prompts_rs-completion-{}", std::process::id()));
+        fs::create_dir_all(dir.join("config")).unwrap();
+        fs::write(dir.join("cargo.toml"), "").unwrap();
+        fs::write(dir.join("readme.md"), "").unwrap();
+        fs::write(dir.join(".cache"), "").unwrap();
+        let input = format!("{}{}", dir.to_string_lossy(), MAIN_SEPARATOR_STR);
+
+        assert_eq!(
+            path_completer(&format!("{}c", input)),
+            [
+                format!("{}cargo.toml", input),
+                format!("{}config{}", input, MAIN_SEPARATOR_STR),
+            ]
+        );
+        assert_eq!(
+            path_completer(&input),
+            [
+                format!("{}cargo.toml", input),
+                format!("{}config{}", input, MAIN_SEPARATOR_STR),
+                format!("{}readme.md", input),
+            ]
+        );
+        assert_eq!(
+            path_completer(&format!("{}.", input)),
+            [format!("{}.cache", input)]
+        );
+        assert!(path_completer(&format!("{}missing{}", input, MAIN_SEPARATOR_STR)).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_path_completer_current_dir() {
+        assert!(path_completer("Cargo.t").contains(&"Cargo.toml".to_string()));
+        assert!(path_completer("sr").contains(&format!("src{}", MAIN_SEPARATOR_STR)));
+    }
+}
@@ -0,0 +1,353 @@
+//! Plain date and time types returned by [`prompt_date`](crate::CliPrompt::prompt_date)
+//! and [`prompt_time`](crate::CliPrompt::prompt_time).
+//!
+//! With the `chrono` feature, they convert from and into `chrono::NaiveDate` and `chrono::NaiveTime`.
+//! Converting a [`Date`] into `NaiveDate` uses `TryFrom`, since `NaiveDate` has a narrower year range.
+//!
+//! # Example
+//! ```
+//! use cli_prompts_rs::datetime::{Date, Time};
+//!
+//! let date = Date::new(2024, 2, 28).unwrap();
+//! assert_eq!(date.add_days(1).to_string(), "2024-02-29");
+//! assert_eq!(date.add_months(1).to_string(), "2024-03-28");
+//! assert!(Date::new(2023, 2, 29).is_none());
+//!
+//! let time = Time::new(9, 30).unwrap();
+//! assert_eq!(time.to_string(), "09:30");
+//! ```
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A date in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    const MIN: Date = Date {
+        year: i32::MIN,
+        month: 1,
+        day: 1,
+    };
+    const MAX: Date = Date {
+        year: i32::MAX,
+        month: 12,
+        day: 31,
+    };
+
+    /// Returns `None` if the date doesn't exist, like February 30th.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current date in UTC, not in the local time zone.
+    ///
+    /// Near midnight, it can be the previous or the next day of the local date.
+    /// Use [`CliPrompt::prompt_date_with_bounds`](crate::CliPrompt::prompt_date_with_bounds) with the local date when it matters,
+    /// such as `chrono::Local::now().date_naive().into()` with the `chrono` feature and chrono's `clock` feature.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the day of the week, from 0 for Sunday to 6 for Saturday.
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 is a Thursday
+        (self.days() + 4).rem_euclid(7) as u32
+    }
+
+    /// Adds the days, saturating at the first day of `i32::MIN` and the last day of `i32::MAX` years.
+    pub fn add_days(&self, days: i64) -> Self {
+        let days = self
+            .days()
+            .saturating_add(days)
+            .clamp(Self::MIN.days(), Self::MAX.days());
+
+        Self::from_days(days)
+    }
+
+    /// Adds the months, clamping the day to the last day of the resulting month.
+    /// Saturates at the first month of `i32::MIN` and the last month of `i32::MAX` years.
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = (i64::from(self.year) * 12 + i64::from(self.month) - 1 + i64::from(months))
+            .clamp(i64::from(i32::MIN) * 12, i64::from(i32::MAX) * 12 + 11);
+        let year = index.div_euclid(12) as i32;
+        let month = index.rem_euclid(12) as u32 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the first day of the month.
+    pub(crate) fn first_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    fn days(&self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A time of the day with minute precision.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// Returns `None` if `hour` is not below 24 or `minute` is not below 60.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        if hour >= 24 || minute >= 60 {
+            return None;
+        }
+
+        Some(Self { hour, minute })
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The error converting a [`Date`] whose year is out of the range of `chrono::NaiveDate`.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DateOutOfRangeError;
+
+#[cfg(feature = "chrono")]
+impl fmt::Display for DateOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "year is out of the range of NaiveDate")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl std::error::Error for DateOutOfRangeError {}
+
+#[cfg(feature = "chrono")]
+mod chrono_conversion {
+    use super::{Date, DateOutOfRangeError, Time};
+    use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+
+    /// Fails if the year is out of the range of `NaiveDate`, which is about ±262,000 years.
+    impl TryFrom<Date> for NaiveDate {
+        type Error = DateOutOfRangeError;
+
+        fn try_from(date: Date) -> Result<Self, Self::Error> {
+            NaiveDate::from_ymd_opt(date.year, date.month, date.day).ok_or(DateOutOfRangeError)
+        }
+    }
+
+    impl From<NaiveDate> for Date {
+        fn from(date: NaiveDate) -> Self {
+            Self {
+                year: date.year(),
+                month: date.month(),
+                day: date.day(),
+            }
+        }
+    }
+
+    impl From<Time> for NaiveTime {
+        fn from(time: Time) -> Self {
+            NaiveTime::from_hms_opt(time.hour, time.minute, 0).expect("Time is always a valid time")
+        }
+    }
+
+    impl From<NaiveTime> for Time {
+        fn from(time: NaiveTime) -> Self {
+            Self {
+                hour: time.hour(),
+                minute: time.minute(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_new() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(2024, 13, 1).is_none());
+        assert!(Date::new(2024, 4, 31).is_none());
+        assert!(Date::new(2024, 1, 0).is_none());
+    }
+
+    #[test]
+    fn test_date_days_round_trip() {
+        let epoch = Date::new(1970, 1, 1).unwrap();
+
+        assert_eq!(epoch.days(), 0);
+        assert_eq!(Date::from_days(0), epoch);
+        assert_eq!(Date::new(2000, 3, 1).unwrap().days(), 11_017);
+        assert_eq!(Date::from_days(-1), Date::new(1969, 12, 31).unwrap());
+
+        for days in (-800_000..800_000).step_by(997) {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+    }
+
+    #[test]
+    fn test_date_weekday() {
+        assert_eq!(Date::new(1970, 1, 1).unwrap().weekday(), 4);
+        assert_eq!(Date::new(2024, 2, 13).unwrap().weekday(), 2);
+        assert_eq!(Date::new(2026, 10, 18).unwrap().weekday(), 0);
+    }
+
+    #[test]
+    fn test_date_arithmetic() {
+        let date = Date::new(2024, 1, 31).unwrap();
+
+        assert_eq!(date.add_days(1), Date::new(2024, 2, 1).unwrap());
+        assert_eq!(date.add_days(-31), Date::new(2023, 12, 31).unwrap());
+        assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+        assert_eq!(date.add_months(-2), Date::new(2023, 11, 30).unwrap());
+        assert_eq!(date.add_months(12), Date::new(2025, 1, 31).unwrap());
+        assert_eq!(date.first_of_month(), Date::new(2024, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_date_arithmetic_saturates() {
+        let last_month = Date::new(i32::MAX, 12, 1).unwrap();
+
+        assert_eq!(last_month.add_months(1), last_month);
+        assert_eq!(last_month.add_months(i32::MAX), last_month);
+        assert_eq!(last_month.add_days(31), Date::MAX);
+        assert_eq!(Date::MAX.add_days(i64::MAX), Date::MAX);
+        assert_eq!(Date::MIN.add_months(-1), Date::MIN);
+        assert_eq!(Date::MIN.add_days(i64::MIN), Date::MIN);
+        assert_eq!(
+            Date::new(i32::MIN, 1, 31).unwrap().add_months(i32::MIN),
+            Date::MIN.add_days(30)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Date::new(987, 6, 5).unwrap().to_string(), "0987-06-05");
+        assert_eq!(Time::new(7, 5).unwrap().to_string(), "07:05");
+        assert!(Time::new(24, 0).is_none());
+        assert!(Time::new(23, 60).is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversion() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let date = Date::new(2024, 2, 29).unwrap();
+        let naive_date = NaiveDate::try_from(date).unwrap();
+
+        assert_eq!(naive_date, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(Date::from(naive_date), date);
+        assert_eq!(
+            NaiveDate::try_from(Date::new(1_000_000, 1, 1).unwrap()),
+            Err(DateOutOfRangeError)
+        );
+
+        let time = Time::new(13, 45).unwrap();
+        let naive_time: NaiveTime = time.into();
+
+        assert_eq!(naive_time, NaiveTime::from_hms_opt(13, 45, 0).unwrap());
+        assert_eq!(Time::from(naive_time), time);
+    }
+}
@@ -0,0 +1,215 @@
+use std::env;
+use std::fs;
+use std::io::Result;
+use std::path::{Path, PathBuf};
+
+/// Default number of entries kept for each history key.
+pub(crate) const DEFAULT_HISTORY_SIZE: usize = 500;
+
+/// Returns the directory where the history files are stored by default, like
+/// `~/.local/share/cli_prompts_rs/history` on Linux.
+pub(crate) fn default_history_dir() -> Option<PathBuf> {
+    data_dir_with(|key| env::var_os(key).map(PathBuf::from))
+        .map(|dir| dir.join("cli_prompts_rs").join("history"))
+}
+
+fn data_dir_with<F>(var: F) -> Option<PathBuf>
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    if cfg!(windows) {
+        return var("APPDATA");
+    }
+
+    if cfg!(target_os = "macos") {
+        return var("HOME").map(|home| home.join("Library").join("Application Support"));
+    }
+
+    var("XDG_DATA_HOME")
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| var("HOME").map(|home| home.join(".local").join("share")))
+}
+
+/// Returns the file name for the history key, replacing characters which are not safe in file names.
+/// The hash of the key is appended, so that keys differing only in the replaced characters don't share the file.
+fn file_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("{}-{:08x}", name, fnv1a(key))
+}
+
+/// 32-bit FNV-1a hash, which stays the same across Rust versions unlike `DefaultHasher`.
+fn fnv1a(key: &str) -> u32 {
+    key.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Entries previously submitted to text prompts, oldest first.
+#[derive(Debug)]
+pub(crate) struct History {
+    path: PathBuf,
+    entries: Vec<String>,
+    max_size: usize,
+}
+
+impl History {
+    /// Loads the history of `key` from `dir`. A missing or unreadable file is an empty history.
+    pub(crate) fn load(dir: &Path, key: &str, max_size: usize) -> Self {
+        let path = dir.join(file_name(key));
+        let entries = fs::read_to_string(&path)
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        let mut history = Self {
+            path,
+            entries,
+            max_size,
+        };
+        history.truncate();
+
+        history
+    }
+
+    pub(crate) fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Adds the entry as the newest one. Empty entries are ignored and duplicates are moved to the end.
+    pub(crate) fn push(&mut self, entry: &str) {
+        if entry.trim().is_empty() {
+            return;
+        }
+        self.entries.retain(|existing| existing != entry);
+        self.entries.push(entry.to_string());
+        self.truncate();
+    }
+
+    /// Returns the index of the newest entry before `before` which contains `query`.
+    pub(crate) fn search(&self, query: &str, before: usize) -> Option<usize> {
+        (0..before.min(self.entries.len()))
+            .rev()
+            .find(|&index| self.entries[index].contains(query))
+    }
+
+    pub(crate) fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut content = self.entries.join("\n");
+        content.push('\n');
+
+        fs::write(&self.path, content)
+    }
+
+    fn truncate(&mut self) {
+        if self.entries.len() > self.max_size {
+            self.entries.drain(..self.entries.len() - self.max_size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("cli_prompts_rs-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_data_dir() {
+        let var = |xdg: Option<&str>| {
+            let xdg = xdg.map(PathBuf::from);
+            move |key: &str| match key {
+                "XDG_DATA_HOME" => xdg.clone(),
+                "HOME" => Some(PathBuf::from("/home/user")),
+                "APPDATA" => Some(PathBuf::from("C:\\Users\\user\\AppData\\Roaming")),
+                _ => None,
+            }
+        };
+
+        if cfg!(windows) {
+            assert_eq!(
+                data_dir_with(var(None)),
+                Some(PathBuf::from("C:\\Users\\user\\AppData\\Roaming"))
+            );
+        } else if cfg!(target_os = "macos") {
+            assert_eq!(
+                data_dir_with(var(None)),
+                Some(PathBuf::from("/home/user/Library/Application Support"))
+            );
+        } else {
+            assert_eq!(
+                data_dir_with(var(None)),
+                Some(PathBuf::from("/home/user/.local/share"))
+            );
+            assert_eq!(
+                data_dir_with(var(Some("/data"))),
+                Some(PathBuf::from("/data"))
+            );
+            assert_eq!(
+                data_dir_with(var(Some("relative"))),
+                Some(PathBuf::from("/home/user/.local/share"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("hosts"), "hosts-29ddeb14");
+        assert!(file_name("deploy/target host").starts_with("deploy_target_host-"));
+        assert_ne!(file_name("a/b"), file_name("a_b"));
+        assert_ne!(file_name("a b"), file_name("a_b"));
+    }
+
+    #[test]
+    fn test_push_and_truncate() {
+        let mut history = History::load(&temp_dir("history-push"), "key", 3);
+
+        for entry in ["a", "b", " ", "c", "a", "d"] {
+            history.push(entry);
+        }
+
+        assert_eq!(history.entries(), ["c", "a", "d"]);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut history = History::load(&temp_dir("history-search"), "key", 10);
+
+        for entry in ["db-1.example.com", "web-1.example.com", "db-2.example.com"] {
+            history.push(entry);
+        }
+
+        assert_eq!(history.search("db", 3), Some(2));
+        assert_eq!(history.search("db", 2), Some(0));
+        assert_eq!(history.search("db", 0), None);
+        assert_eq!(history.search("cache", 3), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = temp_dir("history-save");
+        let mut history = History::load(&dir, "hosts", 10);
+        history.push("localhost");
+        history.push("example.com");
+        history.save().unwrap();
+
+        assert_eq!(
+            History::load(&dir, "hosts", 10).entries(),
+            ["localhost", "example.com"]
+        );
+        assert_eq!(History::load(&dir, "hosts", 1).entries(), ["example.com"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
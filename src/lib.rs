@@ -37,27 +37,47 @@
 //! }
 //! ```
 pub mod cli_prompt_error;
+pub mod color;
+pub mod completion;
+pub mod datetime;
+mod history;
+mod line_buffer;
+pub mod markup;
 mod mock_term;
-// pub mod color;
+mod prompts;
+pub mod theme;
 // pub mod spinner_error;
 
-#[cfg(feature = "mock-term")]
+pub use crate::prompts::autocomplete::AutocompleteOptions;
+pub use crate::prompts::form::{FormField, FormFieldKind, FormValue};
+pub use crate::prompts::group::PromptGroup;
+pub use crate::prompts::grouped::PromptSelectGroup;
+pub use crate::prompts::path::PathPickerOptions;
+pub use crate::prompts::tags::TagsOptions;
+pub use crate::prompts::tree::TreeNode;
+pub use crate::prompts::wizard::{WizardAnswers, WizardStep};
+pub use crate::prompts::Validator;
+
+#[cfg(any(test, feature = "mock-term"))]
 use crate::mock_term::mock_term::{Key, Term};
-use colored::*;
-#[cfg(feature = "mock-term")]
-use console::style;
-#[cfg(not(feature = "mock-term"))]
-use console::{style, Key, Term};
+#[cfg(not(any(test, feature = "mock-term")))]
+use console::{Key, Term};
 use std::fmt;
-use std::io::{Result, Write};
+use std::io::Result;
+use std::path::PathBuf;
 use supports_unicode::Stream;
 
 use crate::cli_prompt_error::CliPromptError::{
     self, InvalidMaxChoiceNumError, OptionsVecEmptyError,
 };
+use crate::color::{ColorMode, Style, StyledText};
+use crate::history::{default_history_dir, DEFAULT_HISTORY_SIZE};
+use crate::theme::{Colors, Theme};
+use console::measure_text_width;
 #[cfg(feature = "unstable")]
 use {
     crate::cli_prompt_error::SpinnerError,
+    std::io::Write,
     std::sync::mpsc::{self, TryRecvError},
     std::thread,
     std::time,
@@ -65,15 +85,19 @@ use {
 };
 
 fn get_symbol(c: &str, fallback: &str, unicode_support: bool) -> String {
-    return if unicode_support {
+    if unicode_support {
         c.to_string()
     } else {
         fallback.to_string()
-    };
+    }
 }
 
 pub struct CliPrompt {
     term: Term,
+    colors: Colors,
+    color_mode: ColorMode,
+    history_dir: Option<PathBuf>,
+    history_size: usize,
     s_bar_start: String,
     s_bar: String,
     s_bar_h: String,
@@ -91,46 +115,142 @@ pub struct CliPrompt {
     s_connect_left: String,
     s_checkbox_active: String,
     s_checkbox_inactive: String,
+    s_password_mask: String,
+    s_tree_collapsed: String,
+    s_tree_expanded: String,
     #[cfg(feature = "unstable")]
     s_spinner_frames: [String; 4],
 }
+
+impl Default for CliPrompt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // TODO: update doc parameter
 // TODO: separate files
 impl CliPrompt {
     pub fn new() -> Self {
+        Self::with_theme(Theme::default())
+    }
+
+    /// Creates `CliPrompt` which prints its symbols and colors with the given [`Theme`].
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - the symbols and colors to use
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::theme::Theme;
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::with_theme(Theme::ascii());
+    /// cli_prompt.intro("example app").unwrap();
+    /// ```
+    pub fn with_theme(theme: Theme) -> Self {
         let unicode_support = supports_unicode::on(Stream::Stdout);
+        let symbols = theme.symbols;
         Self {
             term: Term::stdout(),
-            s_bar_start: get_symbol("┌", "T", unicode_support),
-            s_bar: get_symbol("│", "|", unicode_support),
-            s_bar_h: get_symbol("─", "-", unicode_support),
-            s_bar_end: get_symbol("└", "—", unicode_support),
-            s_radio_active: get_symbol("●", ">", unicode_support),
-            s_radio_inactive: get_symbol("○", " ", unicode_support),
-            s_step_submit: get_symbol("◇", "o", unicode_support),
-            s_info: get_symbol("●", "•", unicode_support),
+            colors: theme.colors,
+            color_mode: ColorMode::Auto,
+            history_dir: default_history_dir(),
+            history_size: DEFAULT_HISTORY_SIZE,
+            s_bar_start: symbols.bar_start.get(unicode_support),
+            s_bar: symbols.bar.get(unicode_support),
+            s_bar_h: symbols.bar_h.get(unicode_support),
+            s_bar_end: symbols.bar_end.get(unicode_support),
+            s_radio_active: symbols.radio_active.get(unicode_support),
+            s_radio_inactive: symbols.radio_inactive.get(unicode_support),
+            s_step_submit: symbols.step_submit.get(unicode_support),
+            s_info: symbols.info.get(unicode_support),
             #[cfg(feature = "unstable")]
-            s_success: get_symbol("◆", "*", unicode_support),
-            s_warn: get_symbol("▲", "!", unicode_support),
-            s_error: get_symbol("■", "x", unicode_support),
-            s_corner_top_right: get_symbol("╮", "+", unicode_support),
-            s_corner_bottom_right: get_symbol("╯", "+", unicode_support),
-            s_connect_left: get_symbol("├", "+", unicode_support),
-            s_checkbox_active: get_symbol("◼", "[+]", unicode_support),
-            s_checkbox_inactive: get_symbol("◻", "[ ]", unicode_support),
+            s_success: symbols.success.get(unicode_support),
+            s_warn: symbols.warn.get(unicode_support),
+            s_error: symbols.error.get(unicode_support),
+            s_corner_top_right: symbols.corner_top_right.get(unicode_support),
+            s_corner_bottom_right: symbols.corner_bottom_right.get(unicode_support),
+            s_connect_left: symbols.connect_left.get(unicode_support),
+            s_checkbox_active: symbols.checkbox_active.get(unicode_support),
+            s_checkbox_inactive: symbols.checkbox_inactive.get(unicode_support),
+            s_password_mask: symbols.password_mask.get(unicode_support),
+            s_tree_collapsed: symbols.tree_collapsed.get(unicode_support),
+            s_tree_expanded: symbols.tree_expanded.get(unicode_support),
             #[cfg(feature = "unstable")]
-            s_spinner_frames: [
-                get_symbol("◒", "•", unicode_support),
-                get_symbol("◐", "o", unicode_support),
-                get_symbol("◓", "O", unicode_support),
-                get_symbol("◑", "0", unicode_support),
-            ],
+            s_spinner_frames: symbols
+                .spinner_frames
+                .map(|frame| frame.get(unicode_support)),
         }
     }
 
-    // pub fn new_with_config() -> Self {
-    //
-    // }
+    /// Overrides whether this instance prints colors.
+    ///
+    /// By default, it follows [`colors_enabled`](color::colors_enabled),
+    /// which honors `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_mode` - the color mode to use
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::color::ColorMode;
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// cli_prompt.set_color_mode(ColorMode::Never);
+    /// cli_prompt.intro("example app").unwrap();
+    /// ```
+    pub fn set_color_mode(&mut self, color_mode: ColorMode) {
+        self.color_mode = color_mode;
+    }
+
+    /// Sets the directory where the history of [`prompt_text_with_history`](CliPrompt::prompt_text_with_history) is stored.
+    ///
+    /// By default, it is `cli_prompts_rs/history` under the user's data directory,
+    /// like `~/.local/share` on Linux, `~/Library/Application Support` on macOS and `%APPDATA%` on Windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - the directory to store history files
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// cli_prompt.set_history_dir("/var/lib/my_app/history");
+    /// let host = cli_prompt.prompt_text_with_history("Host", "hosts").unwrap();
+    /// ```
+    pub fn set_history_dir<P: Into<PathBuf>>(&mut self, dir: P) {
+        self.history_dir = Some(dir.into());
+    }
+
+    /// Sets the maximum number of entries kept for each history key. The oldest entries are dropped first.
+    ///
+    /// Default is 500. `0` disables the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - the maximum number of entries
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// cli_prompt.set_history_size(20);
+    /// let host = cli_prompt.prompt_text_with_history("Host", "hosts").unwrap();
+    /// ```
+    pub fn set_history_size(&mut self, size: usize) {
+        self.history_size = size;
+    }
 
     /// Prints the intro message.
     ///
@@ -150,7 +270,7 @@ impl CliPrompt {
     /// ```
     pub fn intro(&mut self, message: &str) -> std::result::Result<(), CliPromptError> {
         self.term
-            .write_line(format!("{} {}", self.s_bar_start, message).as_str())?;
+            .write_line(format!("{} {}", self.s_bar_start, self.render_markup(message)).as_str())?;
         self.print_empty_line()?;
 
         Ok(())
@@ -174,7 +294,7 @@ impl CliPrompt {
     /// ```
     pub fn outro(&mut self, message: &str) -> std::result::Result<(), CliPromptError> {
         self.term
-            .write_line(format!("{} {}", self.s_bar_end, message).as_str())?;
+            .write_line(format!("{} {}", self.s_bar_end, self.render_markup(message)).as_str())?;
 
         Ok(())
     }
@@ -202,8 +322,14 @@ impl CliPrompt {
     /// }
     /// ```
     pub fn cancel(&mut self, message: &str) -> std::result::Result<(), CliPromptError> {
-        self.term
-            .write_line(format!("{} {}", self.s_bar_end, style(message).red()).as_str())?;
+        self.term.write_line(
+            format!(
+                "{} {}",
+                self.s_bar_end,
+                self.render_styled_markup(&self.colors.cancel, message)
+            )
+            .as_str(),
+        )?;
         Ok(())
     }
 
@@ -235,27 +361,34 @@ impl CliPrompt {
         log_type: LogType,
     ) -> std::result::Result<(), CliPromptError> {
         match log_type {
-            LogType::Info => {
-                self.term
-                    .write_line(&format!("{} {}", self.s_info.blue(), message))?
-            }
-            LogType::Warn => {
-                self.term
-                    .write_line(&format!("{} {}", self.s_warn.yellow(), message.yellow()))?
-            }
-            LogType::Error => {
-                self.term
-                    .write_line(&format!("{} {}", self.s_error.red(), message.red()))?
-            }
+            LogType::Info => self.term.write_line(&format!(
+                "{} {}",
+                self.apply_style(&self.colors.info, &self.s_info),
+                self.render_markup(message)
+            ))?,
+            LogType::Warn => self.term.write_line(&format!(
+                "{} {}",
+                self.apply_style(&self.colors.warn, &self.s_warn),
+                self.render_styled_markup(&self.colors.warn, message)
+            ))?,
+            LogType::Error => self.term.write_line(&format!(
+                "{} {}",
+                self.apply_style(&self.colors.error, &self.s_error),
+                self.render_styled_markup(&self.colors.error, message)
+            ))?,
         }
         self.print_empty_line()?;
 
         Ok(())
     }
 
-    /// Prints the prompt message and read user's input
+    /// Prints the prompt message and let users choose either yes or no.
+    /// Users can change the selection by Arrow Left, Arrow Right and Tab key
+    /// and choose the selection by Enter key. `y` and `n` keys choose Yes and No right away.
     ///
-    /// Returns the input as `String` wrapped in `Result`
+    /// Returns true if user choose Yes.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_confirm_with_default`] with `true`.
     ///
     /// # Arguments
     ///
@@ -267,29 +400,22 @@ impl CliPrompt {
     /// use cli_prompts_rs::CliPrompt;
     ///
     /// let mut cli_prompt = CliPrompt::new();
-    /// let answer = cli_prompt.prompt_text("example app").unwrap();
+    /// let answer = cli_prompt.prompt_confirm("Are you sure?").unwrap();
     /// println!("{}", answer);
     /// ```
-    pub fn prompt_text(&mut self, message: &str) -> std::result::Result<String, CliPromptError> {
-        self.term
-            .write_line(&self.format_prefix(message.to_string(), MessageType::Question))?;
-        self.term.write(format!("{} ", self.s_bar).as_bytes())?;
-
-        let line = self.term.read_line()?;
-        self.print_empty_line()?;
-
-        Ok(line.trim().to_string())
+    pub fn prompt_confirm(&mut self, message: &str) -> std::result::Result<bool, CliPromptError> {
+        self.prompt_confirm_with_default(message, true)
     }
 
-    /// Prints the prompt message and let users choose either yes or no.
-    /// Users can change the selection by Arrow Left and Arrow Right key
-    /// and choose the selection by Enter key.
+    /// Prints the prompt message and let users choose either yes or no, with `default` selected at first.
+    /// The keys are same as [`CliPrompt::prompt_confirm`].
     ///
     /// Returns true if user choose Yes.
     ///
     /// # Arguments
     ///
     /// * `message` - the prompt message
+    /// * `default` - `true` to select Yes at first
     ///
     /// # Examples
     ///
@@ -297,47 +423,24 @@ impl CliPrompt {
     /// use cli_prompts_rs::CliPrompt;
     ///
     /// let mut cli_prompt = CliPrompt::new();
-    /// let answer = cli_prompt.prompt_confirm("Are you sure?").unwrap();
+    /// let answer = cli_prompt
+    ///     .prompt_confirm_with_default("Delete the branch?", false)
+    ///     .unwrap();
     /// println!("{}", answer);
     /// ```
-    pub fn prompt_confirm(&mut self, message: &str) -> std::result::Result<bool, CliPromptError> {
+    pub fn prompt_confirm_with_default(
+        &mut self,
+        message: &str,
+        default: bool,
+    ) -> std::result::Result<bool, CliPromptError> {
         // TODO: when message is empty, get default message
         let prompt_message = if message.is_empty() {
             "Are you sure?"
         } else {
             message
         };
-        let mut choice = 1;
-        self.term.hide_cursor()?;
-        self.term
-            .write_line(&self.format_prefix(prompt_message.to_string(), MessageType::Question))?;
-        self.print_confirm_message(true)?;
-
-        loop {
-            let key = self.term.read_key()?;
-
-            match key {
-                Key::ArrowLeft => {
-                    self.print_confirm_message(true)?;
-                    self.term.flush()?;
-                    choice = 1;
-                }
-                Key::ArrowRight => {
-                    self.print_confirm_message(false)?;
-                    self.term.flush()?;
-                    choice = 0;
-                }
-                Key::Enter => {
-                    self.term.show_cursor()?;
-                    self.term.write_line("")?;
-                    self.print_empty_line()?;
-                    break;
-                }
-                _ => {}
-            }
-        }
 
-        return Ok(choice == 1);
+        self.prompt_toggle_with_default(prompt_message, "Yes", "No", default)
     }
 
     /// Prints the prompt message and let users choose one among the provided options.
@@ -399,7 +502,11 @@ impl CliPrompt {
             let current_option = &options.get(i).unwrap().label;
             if i == 0 {
                 self.term.write_line(&self.format_prefix(
-                    format!("{} {}", self.s_radio_active.green(), current_option),
+                    format!(
+                        "{} {}",
+                        self.apply_style(&self.colors.active, &self.s_radio_active),
+                        current_option
+                    ),
                     MessageType::Option,
                 ))?;
             } else {
@@ -608,7 +715,7 @@ impl CliPrompt {
                 self.term.write_line(&self.format_prefix(
                     format!(
                         "{} {} {}",
-                        self.s_radio_active.green(),
+                        self.apply_style(&self.colors.active, &self.s_radio_active),
                         self.s_checkbox_inactive,
                         current_option
                     ),
@@ -711,8 +818,10 @@ impl CliPrompt {
         // split message by \n
         let split_message = note_message.split("\n");
         // get max length of split messages
-        let max_length_option = split_message.map(|m| m.len()).max();
-        let max_length = max_length_option.unwrap_or_else(|| 0);
+        let max_length_option = split_message
+            .map(|m| measure_text_width(&markup::strip(m)))
+            .max();
+        let max_length = max_length_option.unwrap_or(0);
 
         // print header
         self.term.write_line(
@@ -726,12 +835,12 @@ impl CliPrompt {
         )?;
         // print message
         for message in note_message.split("\n") {
-            let message_length = message.len();
+            let message_length = measure_text_width(&markup::strip(message));
             self.term.write_line(
                 format!(
                     "{} {}{}{}",
                     self.s_bar,
-                    message,
+                    self.render_markup(message),
                     " ".repeat(max_length - message_length + 1),
                     self.s_bar
                 )
@@ -823,19 +932,20 @@ impl CliPrompt {
                 return Err(CliPromptError::SpinnerError(SpinnerError::TimedOut));
             }
 
-            self.term.write(
+            self.term.write_all(
                 format!(
                     "\r{} {}",
-                    self.s_spinner_frames[spinner_symbol_index]
-                        .clone()
-                        .magenta(),
-                    loading_message,
+                    self.apply_style(
+                        &self.colors.spinner,
+                        &self.s_spinner_frames[spinner_symbol_index]
+                    ),
+                    self.render_markup(loading_message),
                 )
                 .as_bytes(),
             )?;
 
             self.term
-                .write(format!("{}", ". ".repeat(spinner_symbol_index + 1)).as_bytes())?;
+                .write_all(". ".repeat(spinner_symbol_index + 1).as_bytes())?;
             self.term.clear_chars(2 * (spinner_symbol_index + 1))?;
 
             thread::sleep(Duration::from_millis(500));
@@ -853,8 +963,14 @@ impl CliPrompt {
         };
         // self.term.write_line("")?;
         self.term.clear_line()?;
-        self.term
-            .write_line(format!("{} {}", self.s_success.green(), finish_message).as_str())?;
+        self.term.write_line(
+            format!(
+                "{} {}",
+                self.apply_style(&self.colors.success, &self.s_success),
+                self.render_markup(finish_message)
+            )
+            .as_str(),
+        )?;
         self.print_empty_line()?;
 
         match task_join_handler.join() {
@@ -867,48 +983,40 @@ impl CliPrompt {
         Ok(())
     }
 
+    fn render_markup(&self, message: &str) -> String {
+        markup::render_with(message, self.color_mode.enabled(), color::color_depth())
+    }
+
+    /// Renders the markup with `style` as the base style, which the text keeps after every closing tag.
+    fn render_styled_markup(&self, style: &Style, message: &str) -> String {
+        markup::render_with_base(
+            message,
+            *style,
+            self.color_mode.enabled(),
+            color::color_depth(),
+        )
+    }
+
+    fn apply_style<D>(&self, style: &Style, val: D) -> StyledText<D> {
+        style.apply_to(val).force_styling(self.color_mode.enabled())
+    }
+
     fn format_prefix(&self, message: String, message_type: MessageType) -> String {
-        return match message_type {
+        match message_type {
             MessageType::Question => {
-                format!("{} {}", self.s_step_submit.magenta(), message)
+                format!(
+                    "{} {}",
+                    self.apply_style(&self.colors.question, &self.s_step_submit),
+                    self.render_markup(&message)
+                )
             }
             MessageType::Option => format!("\r{} {}", self.s_bar, message),
-        };
-    }
-
-    fn print_confirm_message(&mut self, is_yes: bool) -> Result<()> {
-        if is_yes {
-            self.term.write(
-                self.format_prefix(
-                    format!(
-                        "{} Yes / {} No",
-                        self.s_radio_active.green(),
-                        self.s_radio_inactive
-                    ),
-                    MessageType::Option,
-                )
-                .as_bytes(),
-            )?;
-        } else {
-            self.term.write(
-                self.format_prefix(
-                    format!(
-                        "{} Yes / {} No",
-                        self.s_radio_inactive,
-                        self.s_radio_active.green()
-                    ),
-                    MessageType::Option,
-                )
-                .as_bytes(),
-            )?;
         }
-
-        Ok(())
     }
 
     fn print_options(
         &mut self,
-        options: &Vec<PromptSelectOption>,
+        options: &[PromptSelectOption],
         current_choice: usize,
     ) -> Result<()> {
         for i in 0..options.len() {
@@ -916,7 +1024,11 @@ impl CliPrompt {
 
             if i == current_choice {
                 self.term.write_line(&self.format_prefix(
-                    format!("{} {}", self.s_radio_active.green(), current_option),
+                    format!(
+                        "{} {}",
+                        self.apply_style(&self.colors.active, &self.s_radio_active),
+                        current_option
+                    ),
                     MessageType::Option,
                 ))?;
             } else {
@@ -931,30 +1043,32 @@ impl CliPrompt {
     }
 
     fn print_empty_line(&mut self) -> Result<()> {
-        self.term.write_line(format!("{}", self.s_bar).as_str())
+        self.term.write_line(&self.s_bar)
     }
 
     fn print_multi_options(
         &mut self,
-        options: &Vec<PromptSelectOption>,
-        is_selected: &Vec<bool>,
+        options: &[PromptSelectOption],
+        is_selected: &[bool],
         current_choice: usize,
     ) -> Result<()> {
         let options_num = options.len();
 
-        for i in 0..options_num {
-            let current_option = &options.get(i).unwrap().label;
+        for (i, option) in options.iter().enumerate() {
+            let current_option = &option.label;
 
             self.term.write_line(&self.format_prefix(
                 format!(
                     "{} {} {}",
                     if i == current_choice {
-                        self.s_radio_active.green().to_string().clone()
+                        self.apply_style(&self.colors.active, &self.s_radio_active)
+                            .to_string()
                     } else {
                         self.s_radio_inactive.clone()
                     },
                     if is_selected[i] {
-                        self.s_checkbox_active.blue().to_string().clone()
+                        self.apply_style(&self.colors.selected, &self.s_checkbox_active)
+                            .to_string()
                     } else {
                         self.s_checkbox_inactive.clone()
                     },
@@ -976,7 +1090,8 @@ impl CliPrompt {
             format!(
                 "{} {}",
                 if options_num == current_choice {
-                    self.s_radio_active.green().to_string().clone()
+                    self.apply_style(&self.colors.active, &self.s_radio_active)
+                        .to_string()
                 } else {
                     self.s_radio_inactive.clone()
                 },
@@ -987,34 +1102,44 @@ impl CliPrompt {
     }
 
     #[allow(dead_code)]
-    #[cfg(feature = "mock-term")]
+    #[cfg(any(test, feature = "mock-term"))]
     fn get_term_input(&self) -> Vec<u8> {
         self.term.get_input()
     }
 
     #[allow(dead_code)]
-    #[cfg(feature = "mock-term")]
+    #[cfg(any(test, feature = "mock-term"))]
     fn get_term_output(&self) -> Vec<Vec<u8>> {
         self.term.get_output()
     }
 
     #[allow(dead_code)]
-    #[cfg(feature = "mock-term")]
+    #[cfg(any(test, feature = "mock-term"))]
     fn set_term_input(&mut self, input: &str) {
         self.term.input = input.to_string().into_bytes();
+        // the input is piped like from a file, not typed on a terminal
+        self.term.is_term = false;
     }
 
     #[allow(dead_code)]
-    #[cfg(feature = "mock-term")]
+    #[cfg(any(test, feature = "mock-term"))]
     fn clear_term_output(&mut self) {
         self.term.clear_output();
     }
 
     #[allow(dead_code)]
-    #[cfg(feature = "mock-term")]
+    #[cfg(any(test, feature = "mock-term"))]
     fn push_key_input(&mut self, key: &str) {
         self.term.key_input.push_back(key.to_string());
     }
+
+    #[allow(dead_code)]
+    #[cfg(any(test, feature = "mock-term"))]
+    fn push_key_inputs(&mut self, keys: &[&str]) {
+        for key in keys {
+            self.push_key_input(key);
+        }
+    }
 }
 
 enum MessageType {
@@ -1058,13 +1183,15 @@ impl fmt::Display for PromptSelectOption {
 
 // #[cfg(feature = "mock-term")]
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use console::style;
     use std::collections::HashMap;
     // #[cfg(feature = "unstable")]
     // use std::io::{Error, ErrorKind};
 
-    fn build_prefix_map() -> HashMap<String, String> {
+    pub(crate) fn build_prefix_map() -> HashMap<String, String> {
         let unicode_support = supports_unicode::on(Stream::Stdout);
         let mut prefix_map = HashMap::new();
 
@@ -1075,7 +1202,7 @@ mod tests {
         prefix_map.insert("s_bar".to_owned(), get_symbol("│", "|", unicode_support));
         prefix_map.insert(
             "s_bar_end".to_owned(),
-            get_symbol("└", "—", unicode_support),
+            get_symbol("└", "-", unicode_support),
         );
         prefix_map.insert(
             "s_radio_active".to_owned(),
@@ -1089,7 +1216,7 @@ mod tests {
             "s_step_submit".to_owned(),
             get_symbol("◇", "o", unicode_support),
         );
-        prefix_map.insert("s_info".to_owned(), get_symbol("●", "•", unicode_support));
+        prefix_map.insert("s_info".to_owned(), get_symbol("●", "*", unicode_support));
         prefix_map.insert("s_warn".to_owned(), get_symbol("▲", "!", unicode_support));
         prefix_map.insert("s_error".to_owned(), get_symbol("■", "x", unicode_support));
         prefix_map.insert("s_bar_h".to_owned(), get_symbol("─", "-", unicode_support));
@@ -1113,6 +1240,14 @@ mod tests {
             "s_checkbox_inactive".to_owned(),
             get_symbol("◻", "[ ]", unicode_support),
         );
+        prefix_map.insert(
+            "s_tree_collapsed".to_owned(),
+            get_symbol("▸", "+", unicode_support),
+        );
+        prefix_map.insert(
+            "s_tree_expanded".to_owned(),
+            get_symbol("▾", "-", unicode_support),
+        );
 
         prefix_map
     }
@@ -1132,7 +1267,7 @@ mod tests {
         let result = cli_prompt.format_prefix("test message".to_string(), MessageType::Question);
 
         let unicode_support = supports_unicode::on(Stream::Stdout);
-        let prefix = get_symbol("◇", "o", unicode_support).magenta();
+        let prefix = style(get_symbol("◇", "o", unicode_support)).magenta();
         assert_eq!(result, format!("{} {}", prefix, "test message"));
     }
 
@@ -1164,6 +1299,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_intro_with_ascii_theme() {
+        let mut cli_prompt = CliPrompt::with_theme(Theme::ascii());
+        cli_prompt.intro("message").unwrap();
+
+        assert_eq!("T message\n|\n", cli_prompt.term.get_output_string());
+    }
+
     #[test]
     fn test_outro() {
         let mut cli_prompt = CliPrompt::new();
@@ -1245,25 +1388,92 @@ mod tests {
     }
 
     #[test]
-    fn test_prompt_text() {
+    fn test_log_with_color_mode() {
         let prefix_map = build_prefix_map();
 
         let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Always);
+        cli_prompt.log("message", LogType::Error).unwrap();
 
-        cli_prompt.set_term_input("my name");
+        assert_eq!(
+            format!(
+                "{} {}\n{}\n",
+                style(prefix_map.get("s_error").unwrap())
+                    .red()
+                    .force_styling(true),
+                style("message").red().force_styling(true),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
 
-        let result = cli_prompt.prompt_text("name?").unwrap();
+        cli_prompt.clear_term_output();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        cli_prompt.log("message", LogType::Error).unwrap();
 
         assert_eq!(
             format!(
-                "{} name?\n{} {}\n",
-                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
-                prefix_map.get("s_bar").unwrap(),
+                "{} message\n{}\n",
+                prefix_map.get("s_error").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_log_with_markup() {
+        let prefix_map = build_prefix_map();
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        cli_prompt
+            .log("[bold]file[/] \\[1] not found", LogType::Info)
+            .unwrap();
+
+        assert_eq!(
+            format!(
+                "{} file [1] not found\n{}\n",
+                prefix_map.get("s_info").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+
+        cli_prompt.clear_term_output();
+        cli_prompt.set_color_mode(ColorMode::Always);
+        cli_prompt.intro("[bold]app[/]").unwrap();
+
+        assert_eq!(
+            format!(
+                "{} \x1b[1mapp\x1b[0m\n{}\n",
+                prefix_map.get("s_bar_start").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_log_with_markup_keeps_log_color() {
+        let prefix_map = build_prefix_map();
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Always);
+        cli_prompt
+            .log("[bold]file[/] not found", LogType::Warn)
+            .unwrap();
+
+        assert_eq!(
+            format!(
+                "{} \x1b[1;33mfile\x1b[0m\x1b[33m not found\x1b[0m\n{}\n",
+                style(prefix_map.get("s_warn").unwrap())
+                    .yellow()
+                    .force_styling(true),
                 prefix_map.get("s_bar").unwrap()
             ),
             cli_prompt.term.get_output_string()
         );
-        assert_eq!(result, "my name".to_string());
     }
 
     #[test]
@@ -1276,12 +1486,10 @@ mod tests {
 
         assert_eq!(
             format!(
-                "{} {}\n\r{} {} Yes / {} No\n{}\n",
+                "{} {}\n{} Yes\n{}\n",
                 style(prefix_map.get("s_step_submit").unwrap()).magenta(),
                 "message",
                 prefix_map.get("s_bar").unwrap(),
-                style(prefix_map.get("s_radio_active").unwrap()).green(),
-                prefix_map.get("s_radio_inactive").unwrap(),
                 prefix_map.get("s_bar").unwrap()
             ),
             cli_prompt.term.get_output_string()
@@ -0,0 +1,206 @@
+/// Editable line of text with a cursor, used by the key-driven prompts.
+///
+/// The cursor is an index of `chars`, so multibyte characters are edited as a whole.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct LineBuffer {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+impl LineBuffer {
+    pub(crate) fn new(text: &str) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let cursor = chars.len();
+        Self { chars, cursor }
+    }
+
+    pub(crate) fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    pub(crate) fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub(crate) fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(self.chars.len());
+    }
+
+    pub(crate) fn insert(&mut self, c: char) {
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+    }
+
+    /// Deletes the character before the cursor. Returns false if the cursor is at the start.
+    pub(crate) fn delete_backward(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        self.chars.remove(self.cursor);
+        true
+    }
+
+    /// Deletes the character under the cursor. Returns false if the cursor is at the end.
+    pub(crate) fn delete_forward(&mut self) -> bool {
+        if self.cursor == self.chars.len() {
+            return false;
+        }
+        self.chars.remove(self.cursor);
+        true
+    }
+
+    pub(crate) fn move_left(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        true
+    }
+
+    pub(crate) fn move_right(&mut self) -> bool {
+        if self.cursor == self.chars.len() {
+            return false;
+        }
+        self.cursor += 1;
+        true
+    }
+
+    pub(crate) fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub(crate) fn move_end(&mut self) {
+        self.cursor = self.chars.len();
+    }
+
+    /// Deletes the word before the cursor along with the whitespace following it, like Ctrl+W in shells.
+    pub(crate) fn delete_word_backward(&mut self) {
+        let mut start = self.cursor;
+        while start > 0 && self.chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        while start > 0 && !self.chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        self.chars.drain(start..self.cursor);
+        self.cursor = start;
+    }
+
+    pub(crate) fn delete_to_start(&mut self) {
+        self.chars.drain(..self.cursor);
+        self.cursor = 0;
+    }
+
+    pub(crate) fn delete_to_end(&mut self) {
+        self.chars.truncate(self.cursor);
+    }
+
+    /// Splits the line at the cursor, keeping the text before it and returning the rest.
+    pub(crate) fn split_off(&mut self) -> LineBuffer {
+        let rest = self.chars.split_off(self.cursor);
+        LineBuffer {
+            chars: rest,
+            cursor: 0,
+        }
+    }
+
+    /// Appends the other line, keeping the cursor where it is.
+    pub(crate) fn append(&mut self, other: &LineBuffer) {
+        self.chars.extend_from_slice(&other.chars);
+    }
+
+    /// Returns the text before the cursor, the character under it and the text after it.
+    pub(crate) fn split_at_cursor(&self) -> (String, Option<char>, String) {
+        let before = self.chars[..self.cursor].iter().collect();
+        let current = self.chars.get(self.cursor).copied();
+        let after = self
+            .chars
+            .get(self.cursor + 1..)
+            .map(|chars| chars.iter().collect())
+            .unwrap_or_default();
+
+        (before, current, after)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_delete() {
+        let mut buffer = LineBuffer::new("héllo");
+
+        assert_eq!(buffer.cursor(), 5);
+
+        buffer.move_left();
+        buffer.move_left();
+        buffer.insert('한');
+
+        assert_eq!(buffer.text(), "hél한lo");
+
+        assert!(buffer.delete_backward());
+        assert!(buffer.delete_forward());
+        assert_eq!(buffer.text(), "hélo");
+
+        buffer.move_home();
+        assert!(!buffer.delete_backward());
+        buffer.move_end();
+        assert!(!buffer.delete_forward());
+    }
+
+    #[test]
+    fn test_delete_word_and_line() {
+        let mut buffer = LineBuffer::new("git  commit 메시지  ");
+
+        buffer.delete_word_backward();
+        assert_eq!(buffer.text(), "git  commit ");
+
+        buffer.delete_word_backward();
+        assert_eq!(buffer.text(), "git  ");
+
+        buffer.insert('x');
+        buffer.move_left();
+        buffer.delete_to_end();
+        assert_eq!(buffer.text(), "git  ");
+
+        buffer.set_cursor(2);
+        buffer.delete_to_start();
+        assert_eq!(buffer.text(), "t  ");
+        assert_eq!(buffer.cursor(), 0);
+    }
+
+    #[test]
+    fn test_split_and_append() {
+        let mut buffer = LineBuffer::new("hello world");
+        buffer.set_cursor(5);
+
+        let mut rest = buffer.split_off();
+
+        assert_eq!(buffer.text(), "hello");
+        assert_eq!(rest.text(), " world");
+        assert_eq!(rest.cursor(), 0);
+
+        rest.move_end();
+        buffer.append(&rest);
+        assert_eq!(buffer.text(), "hello world");
+        assert_eq!(buffer.cursor(), 5);
+    }
+
+    #[test]
+    fn test_split_at_cursor() {
+        let mut buffer = LineBuffer::new("abc");
+
+        assert_eq!(
+            buffer.split_at_cursor(),
+            ("abc".to_string(), None, String::new())
+        );
+
+        buffer.set_cursor(1);
+        assert_eq!(
+            buffer.split_at_cursor(),
+            ("a".to_string(), Some('b'), "c".to_string())
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Inline markup to style messages.
+//!
+//! A tag like `[bold red]` styles the text until the matching `[/]`.
+//! Every message taking method of [`CliPrompt`](crate::CliPrompt) accepts markup,
+//! and prints plain text when colors are disabled.
+//!
+//! # Tags
+//! - attributes: `bold`, `dim`, `italic`, `underline`, `strikethrough`, `reverse`
+//! - colors: `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan`, `white`
+//!   and their `bright_` variants, `color(0..=255)`, hex colors like `#ff8800`
+//!   and CSS names like `orange`
+//! - background: `on` followed by a color, like `on blue`
+//!
+//! Tags can be nested. `[/]` closes the last opened tag.
+//! Brackets which are not a valid tag are printed as is. `\[` prints a literal `[`, and `\\` prints a literal `\`.
+//! Use [`escape`] for text that should never be styled, such as user input.
+//!
+//! # Example
+//! ```no_run
+//! use cli_prompts_rs::markup;
+//! use cli_prompts_rs::{CliPrompt, LogType};
+//!
+//! let path = "config[1].toml";
+//! let mut cli_prompt = CliPrompt::new();
+//! cli_prompt
+//!     .log(
+//!         &format!("[bold red]Error:[/] file [underline]{}[/]", markup::escape(path)),
+//!         LogType::Error,
+//!     )
+//!     .unwrap();
+//!
+//! println!("{}", markup::render("[bold #ff8800 on black]orange[/]"));
+//! ```
+use crate::color::{color_depth, colors_enabled, Color, ColorDepth, Rgb, Style};
+
+fn parse_color(token: &str) -> Option<Color> {
+    const BASIC: [Color; 8] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ];
+    const BRIGHT: [Color; 8] = [
+        Color::BrightBlack,
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+        Color::BrightWhite,
+    ];
+    const NAMES: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+    let token = token.to_ascii_lowercase();
+
+    if let Some(index) = NAMES.iter().position(|name| *name == token) {
+        return Some(BASIC[index]);
+    }
+
+    if let Some(name) = token.strip_prefix("bright_") {
+        return NAMES
+            .iter()
+            .position(|basic| *basic == name)
+            .map(|index| BRIGHT[index]);
+    }
+
+    if let Some(number) = token
+        .strip_prefix("color(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return number.parse().ok().map(Color::Color256);
+    }
+
+    if token.starts_with('#') {
+        return Rgb::from_hex(&token).map(Color::Rgb);
+    }
+
+    Rgb::from_name(&token).map(Color::Rgb)
+}
+
+fn parse_style(spec: &str) -> Option<Style> {
+    let mut style = Style::new();
+    let mut tokens = spec.split_whitespace();
+    let mut is_empty = true;
+
+    while let Some(token) = tokens.next() {
+        is_empty = false;
+        style = match token.to_ascii_lowercase().as_str() {
+            "bold" => style.bold(),
+            "dim" => style.dim(),
+            "italic" => style.italic(),
+            "underline" => style.underline(),
+            "strikethrough" => style.strikethrough(),
+            "reverse" => style.reverse(),
+            "on" => style.bg(parse_color(tokens.next()?)?),
+            _ => style.fg(parse_color(token)?),
+        };
+    }
+
+    if is_empty {
+        return None;
+    }
+
+    Some(style)
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Token {
+    Text(String),
+    Open(Style),
+    Close,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut buffer = String::new();
+    let mut depth = 0;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            if let Some(escaped @ ('[' | '\\')) = rest[1..].chars().next() {
+                buffer.push(escaped);
+                rest = &rest[2..];
+                continue;
+            }
+        }
+
+        if c == '[' {
+            if let Some(end) = rest.find(']') {
+                let spec = &rest[1..end];
+                let token = if spec == "/" || (spec.starts_with('/') && depth > 0) {
+                    (depth > 0).then_some(Token::Close)
+                } else {
+                    parse_style(spec).map(Token::Open)
+                };
+
+                if let Some(token) = token {
+                    if !buffer.is_empty() {
+                        tokens.push(Token::Text(std::mem::take(&mut buffer)));
+                    }
+                    match token {
+                        Token::Open(_) => depth += 1,
+                        Token::Close => depth -= 1,
+                        Token::Text(_) => {}
+                    }
+                    tokens.push(token);
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+
+        buffer.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if !buffer.is_empty() {
+        tokens.push(Token::Text(buffer));
+    }
+
+    tokens
+}
+
+pub(crate) fn render_with(text: &str, enabled: bool, depth: ColorDepth) -> String {
+    render_with_base(text, Style::new(), enabled, depth)
+}
+
+/// Renders the markup with `base` applied to the whole text, so that the text after a tag keeps it.
+pub(crate) fn render_with_base(
+    text: &str,
+    base: Style,
+    enabled: bool,
+    depth: ColorDepth,
+) -> String {
+    let mut stack: Vec<Style> = vec![];
+    let mut output = String::new();
+
+    for token in tokenize(text) {
+        match token {
+            Token::Text(text) => {
+                let current = stack.last().copied().unwrap_or(base);
+                if enabled && !current.is_plain() {
+                    output.push_str(&format!("\x1b[{}m{}\x1b[0m", current.sgr(depth), text));
+                } else {
+                    output.push_str(&text);
+                }
+            }
+            Token::Open(style) => {
+                let current = stack.last().copied().unwrap_or(base);
+                stack.push(current.merge(&style));
+            }
+            Token::Close => {
+                stack.pop();
+            }
+        }
+    }
+
+    output
+}
+
+/// Renders the markup with the colors, following [`colors_enabled`].
+pub fn render(text: &str) -> String {
+    render_with(text, colors_enabled(), color_depth())
+}
+
+/// Removes every tag and returns the plain text.
+pub fn strip(text: &str) -> String {
+    render_with(text, false, ColorDepth::Ansi16)
+}
+
+/// Escapes the text so that it is printed as is.
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('[', "\\[")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_plain() {
+        assert_eq!(
+            render_with("[bold red]Error:[/] file", false, ColorDepth::Ansi16),
+            "Error: file"
+        );
+    }
+
+    #[test]
+    fn test_render_styles() {
+        assert_eq!(
+            render_with("[bold red]Error:[/] file", true, ColorDepth::Ansi16),
+            "\x1b[1;31mError:\x1b[0m file"
+        );
+        assert_eq!(
+            render_with("[on bright_blue]a[/]", true, ColorDepth::Ansi16),
+            "\x1b[104ma\x1b[0m"
+        );
+        assert_eq!(
+            render_with("[#ff8800]a[/][color(42)]b[/]", true, ColorDepth::TrueColor),
+            "\x1b[38;2;255;136;0ma\x1b[0m\x1b[38;5;42mb\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_render_nested() {
+        assert_eq!(
+            render_with("[red]a[underline]b[/]c[/]d", true, ColorDepth::Ansi16),
+            "\x1b[31ma\x1b[0m\x1b[4;31mb\x1b[0m\x1b[31mc\x1b[0md"
+        );
+    }
+
+    #[test]
+    fn test_render_with_base() {
+        assert_eq!(
+            render_with_base(
+                "a[bold]b[/]c",
+                Style::new().fg(Color::Red),
+                true,
+                ColorDepth::Ansi16
+            ),
+            "\x1b[31ma\x1b[0m\x1b[1;31mb\x1b[0m\x1b[31mc\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_invalid_tags_are_literal() {
+        assert_eq!(strip("Continue? [y/N]"), "Continue? [y/N]");
+        assert_eq!(strip("[] [/] [on] [bold nope]"), "[] [/] [on] [bold nope]");
+        assert_eq!(strip("unclosed [bold"), "unclosed [bold");
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(strip("\\[bold]"), "[bold]");
+        assert_eq!(strip(&escape("[red]path[/]")), "[red]path[/]");
+        assert_eq!(strip(&format!("[red]{}[/]", escape("a[1]"))), "a[1]");
+    }
+
+    #[test]
+    fn test_escape_round_trip() {
+        for text in ["C:\\dir\\", "a\\[b]", "\\\\[", "[/] \\"] {
+            assert_eq!(strip(&escape(text)), text);
+            assert_eq!(
+                strip(&format!("[bold]{}[/] [red]x[/]", escape(text))),
+                format!("{} x", text)
+            );
+        }
+        assert_eq!(strip("a\\b"), "a\\b");
+    }
+}
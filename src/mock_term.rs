@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub(crate) mod mock_term {
     use crate::mock_term::mock_term;
     use std::collections::VecDeque;
@@ -9,6 +10,9 @@ pub(crate) mod mock_term {
         pub input: Vec<u8>,
         pub output: Vec<Vec<u8>>,
         pub key_input: VecDeque<String>,
+        pub is_term: bool,
+        /// Rows and columns of the terminal. The output is kept by lines, so the default is wide enough not to wrap them.
+        pub size: (u16, u16),
     }
 
     #[allow(dead_code)]
@@ -23,6 +27,8 @@ pub(crate) mod mock_term {
                 input: vec![],
                 output: initial_output,
                 key_input: VecDeque::new(),
+                is_term: true,
+                size: (24, u16::MAX),
             }
         }
 
@@ -33,11 +39,13 @@ pub(crate) mod mock_term {
                 input: vec![],
                 output: vec![],
                 key_input: VecDeque::new(),
+                is_term: true,
+                size: (24, u16::MAX),
             }
         }
 
         pub fn write_line(&mut self, s: &str) -> Result<(), std::io::Error> {
-            self.write(s.as_bytes())?;
+            self.write_all(s.as_bytes())?;
             self.current_cursor.0 += 1;
             self.current_cursor.1 = 0;
 
@@ -77,20 +85,55 @@ pub(crate) mod mock_term {
                 return Ok(Key::Enter);
             }
 
-            match input_key_option.unwrap().as_str() {
+            let input_key = input_key_option.unwrap();
+            let mut chars = input_key.chars();
+
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                return Ok(Key::Char(c));
+            }
+
+            match input_key.as_str() {
                 "arrow left" => Ok(Key::ArrowLeft),
                 "arrow right" => Ok(Key::ArrowRight),
                 "arrow down" => Ok(Key::ArrowDown),
                 "arrow up" => Ok(Key::ArrowUp),
                 "enter" => Ok(Key::Enter),
+                "alt enter" => Ok(Key::UnknownEscSeq(vec!['\r'])),
+                "esc [1;" => Ok(Key::UnknownEscSeq(vec!['[', '1', ';'])),
+                "ctrl c" => Ok(Key::CtrlC),
+                "escape" => Ok(Key::Escape),
+                "backspace" => Ok(Key::Backspace),
+                "del" => Ok(Key::Del),
+                "home" => Ok(Key::Home),
+                "end" => Ok(Key::End),
+                "tab" => Ok(Key::Tab),
+                "backtab" => Ok(Key::BackTab),
+                "page up" => Ok(Key::PageUp),
+                "page down" => Ok(Key::PageDown),
                 _ => Ok(Key::Unknown),
             }
         }
 
+        pub fn read_key_raw(&mut self) -> Result<mock_term::Key, std::io::Error> {
+            self.read_key()
+        }
+
         pub fn read_line(&self) -> Result<String, std::io::Error> {
             Ok(String::from_utf8(self.input.clone()).unwrap())
         }
 
+        pub fn read_secure_line(&self) -> Result<String, std::io::Error> {
+            self.read_line()
+        }
+
+        pub fn is_term(&self) -> bool {
+            self.is_term
+        }
+
+        pub fn size(&self) -> (u16, u16) {
+            self.size
+        }
+
         // TODO: need mock implementation for testing message
         pub fn move_cursor_down(&mut self, n: usize) -> Result<(), std::io::Error> {
             self.current_cursor.0 = if self.output.len() >= n + self.current_cursor.0 {
@@ -104,11 +147,7 @@ pub(crate) mod mock_term {
 
         // TODO: need mock implementation for testing message
         pub fn move_cursor_up(&mut self, n: usize) -> Result<(), std::io::Error> {
-            self.current_cursor.0 = if self.current_cursor.0 >= n {
-                self.current_cursor.0 - n
-            } else {
-                0
-            };
+            self.current_cursor.0 = self.current_cursor.0.saturating_sub(n);
 
             Ok(())
         }
@@ -121,6 +160,18 @@ pub(crate) mod mock_term {
             Ok(())
         }
 
+        pub fn flush(&mut self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+
+        pub fn clear_last_lines(&mut self, n: usize) -> Result<(), std::io::Error> {
+            self.move_cursor_up(n)?;
+            self.output.truncate(self.current_cursor.0);
+            self.current_cursor.1 = 0;
+
+            Ok(())
+        }
+
         pub fn get_output_string(&self) -> String {
             self.output
                 .iter()
@@ -177,7 +228,7 @@ pub(crate) mod mock_term {
                 }
 
                 if buf_str_split_vec.len() > index + 1 {
-                    self.current_cursor.0 = self.current_cursor.0 + 1;
+                    self.current_cursor.0 += 1;
                     self.current_cursor.1 = 0;
                 } else {
                     // cursor remain
@@ -217,10 +268,12 @@ pub(crate) mod mock_term {
         PageUp,
         PageDown,
         Char(char),
+        CtrlC,
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use crate::mock_term::mock_term::{Term, Key};
     use std::io::Write;
@@ -263,7 +316,7 @@ mod tests {
     fn test_write() {
         let mut mock_term = Term::stdout();
 
-        mock_term.write(b"hahaha").unwrap();
+        mock_term.write_all(b"hahaha").unwrap();
 
         assert_eq!(mock_term.get_output_string(), "hahaha");
         assert_eq!(mock_term.get_current_cursor(), (0, 6));
@@ -274,7 +327,7 @@ mod tests {
         let initial_output: Vec<Vec<u8>> = vec![b"qweqwe".to_vec(), b"qqq".to_vec()];
         let mut mock_term = Term::stdout_with_output_and_cursor(initial_output, (2, 0));
 
-        mock_term.write(b"hello\nthere").unwrap();
+        mock_term.write_all(b"hello\nthere").unwrap();
 
         assert_eq!(mock_term.get_output_string(), "qweqwe\nqqq\nhello\nthere");
         assert_eq!(mock_term.get_current_cursor(), (3, 5));
@@ -285,7 +338,7 @@ mod tests {
         let initial_output: Vec<Vec<u8>> = vec![b"qweqwe".to_vec(), b"qqq".to_vec()];
         let mut mock_term = Term::stdout_with_output_and_cursor(initial_output, (1, 3));
 
-        mock_term.write(b"hello\nthere").unwrap();
+        mock_term.write_all(b"hello\nthere").unwrap();
 
         assert_eq!(mock_term.get_output_string(), "qweqwe\nqqqhello\nthere");
         assert_eq!(mock_term.get_current_cursor(), (2, 5));
@@ -296,7 +349,7 @@ mod tests {
         let initial_output: Vec<Vec<u8>> = vec![b"qweqwe".to_vec(), b"qqq".to_vec()];
         let mut mock_term = Term::stdout_with_output_and_cursor(initial_output, (1, 3));
 
-        mock_term.write(b"\rhello\nthere").unwrap();
+        mock_term.write_all(b"\rhello\nthere").unwrap();
 
         assert_eq!(mock_term.get_output_string(), "qweqwe\n\rhello\nthere");
         assert_eq!(mock_term.get_current_cursor(), (2, 5));
@@ -308,7 +361,7 @@ mod tests {
         let initial_output: Vec<Vec<u8>> = vec![b"qweqwe".to_vec(), b"qqqxxxxxx".to_vec()];
         let mut mock_term = Term::stdout_with_output_and_cursor(initial_output, (1, 9));
 
-        mock_term.write(b"\rhello\nthere").unwrap();
+        mock_term.write_all(b"\rhello\nthere").unwrap();
 
         assert_eq!(mock_term.get_output_string(), "qweqwe\n\rhelloxxx\nthere");
         assert_eq!(mock_term.get_current_cursor(), (2, 5));
@@ -355,7 +408,7 @@ mod tests {
         assert_eq!(mock_term.get_current_cursor(), (2, 9));
 
         mock_term.move_cursor_up(2).unwrap();
-        mock_term.write(b"aaa\naa").unwrap();
+        mock_term.write_all(b"aaa\naa").unwrap();
 
         assert_eq!(mock_term.get_output_string(), "qweqwe   aaa\naaqxxxxxx");
         assert_eq!(mock_term.get_current_cursor(), (1, 2));
@@ -435,6 +488,49 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_read_key_editing_keys() {
+        let mut mock_term = Term::stdout();
+
+        for key in ["a", "\x04", "backspace", "escape", "tab", "alt enter", "page up"] {
+            mock_term.key_input.push_back(key.to_string());
+        }
+
+        let mut read_key_result_vec = vec![];
+
+        for _ in 0..7 {
+            read_key_result_vec.push(mock_term.read_key().unwrap());
+        }
+
+        assert_eq!(
+            read_key_result_vec,
+            vec![
+                Key::Char('a'),
+                Key::Char('\x04'),
+                Key::Backspace,
+                Key::Escape,
+                Key::Tab,
+                Key::UnknownEscSeq(vec!['\r']),
+                Key::PageUp,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clear_last_lines() {
+        let initial_output: Vec<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+        let mut mock_term = Term::stdout_with_output_and_cursor(initial_output, (3, 0));
+
+        mock_term.clear_last_lines(2).unwrap();
+
+        assert_eq!(mock_term.get_output_string(), "a\n");
+        assert_eq!(mock_term.get_current_cursor(), (1, 0));
+
+        mock_term.write_line("d").unwrap();
+
+        assert_eq!(mock_term.get_output_string(), "a\nd\n");
+    }
+
     #[test]
     fn test_clear_chars() {
         let mock_term = Term::stdout();
@@ -0,0 +1,161 @@
+//! Key-driven prompts which redraw their whole frame on every key press.
+use crate::color::Style;
+use crate::line_buffer::LineBuffer;
+use crate::{CliPrompt, Key};
+use console::measure_text_width;
+use std::io::Result;
+
+pub(crate) mod autocomplete;
+mod confirm_typed;
+mod datetime;
+mod editor;
+mod expand;
+pub(crate) mod form;
+pub(crate) mod group;
+pub(crate) mod grouped;
+mod multiline;
+pub(crate) mod path;
+mod slider;
+mod sortable;
+mod table;
+pub(crate) mod tags;
+mod text;
+mod toggle;
+pub(crate) mod tree;
+pub(crate) mod wizard;
+
+pub(crate) const CTRL_D: char = '\x04';
+pub(crate) const CTRL_K: char = '\x0b';
+pub(crate) const CTRL_R: char = '\x12';
+pub(crate) const CTRL_U: char = '\x15';
+pub(crate) const CTRL_W: char = '\x17';
+
+/// Checks a value entered by users, returning the error message to show if it is invalid.
+pub type Validator = Box<dyn Fn(&str) -> std::result::Result<(), String>>;
+
+/// Returns the buffer with every character replaced by `mask`, keeping the cursor position.
+fn mask(buffer: &LineBuffer, mask: &str) -> LineBuffer {
+    let mut masked = LineBuffer::new(&mask.repeat(buffer.text().chars().count()));
+    masked.set_cursor(buffer.cursor() * mask.chars().count());
+
+    masked
+}
+
+/// Returns the number of terminal rows the lines take, counting the rows of the lines wrapped at `width` columns.
+fn frame_height(lines: &[String], width: usize) -> usize {
+    let width = width.max(1);
+
+    lines
+        .iter()
+        .map(|line| measure_text_width(line).div_ceil(width).max(1))
+        .sum()
+}
+
+/// Applies the line editing key to the buffer. Ctrl+A and Ctrl+E arrive as Home and End keys.
+///
+/// Returns false if the key is not a line editing key.
+fn edit_line(buffer: &mut LineBuffer, key: &Key) -> bool {
+    match key {
+        Key::ArrowLeft => {
+            buffer.move_left();
+        }
+        Key::ArrowRight => {
+            buffer.move_right();
+        }
+        Key::Home => buffer.move_home(),
+        Key::End => buffer.move_end(),
+        Key::Backspace => {
+            buffer.delete_backward();
+        }
+        Key::Del => {
+            buffer.delete_forward();
+        }
+        Key::Char(CTRL_W) => buffer.delete_word_backward(),
+        Key::Char(CTRL_U) => buffer.delete_to_start(),
+        Key::Char(CTRL_K) => buffer.delete_to_end(),
+        Key::Char(c) if !c.is_control() => buffer.insert(*c),
+        _ => return false,
+    }
+
+    true
+}
+
+impl CliPrompt {
+    /// Clears the previously drawn frame and draws `lines` in its place.
+    ///
+    /// Returns the height of the new frame in terminal rows, including the rows of wrapped lines,
+    /// which must be passed as `previous_height` on the next call.
+    fn draw_frame(&mut self, lines: &[String], previous_height: usize) -> Result<usize> {
+        if previous_height > 0 {
+            self.term.clear_last_lines(previous_height)?;
+        }
+        for line in lines {
+            self.term.write_line(line)?;
+        }
+        self.term.flush()?;
+
+        Ok(frame_height(lines, self.term.size().1 as usize))
+    }
+
+    /// Reads the lines of stdin until EOF, for the prompts used without a terminal.
+    fn read_lines_to_end(&self) -> Result<Vec<String>> {
+        #[cfg(not(any(test, feature = "mock-term")))]
+        return std::io::stdin().lines().collect();
+
+        #[cfg(any(test, feature = "mock-term"))]
+        return Ok(self.term.read_line()?.lines().map(str::to_string).collect());
+    }
+
+    /// Formats the line of the frame with the bar in front of it.
+    fn format_bar_line(&self, text: &str) -> String {
+        format!("{} {}", self.s_bar, text)
+    }
+
+    /// Formats the text with the cursor drawn in reverse video.
+    fn format_cursor_line(&self, buffer: &LineBuffer) -> String {
+        let (before, current, after) = buffer.split_at_cursor();
+
+        format!(
+            "{}{}{}",
+            before,
+            self.apply_style(&Style::new().reverse(), current.unwrap_or(' ')),
+            after
+        )
+    }
+
+    /// Formats the option of the select-like prompts, like [`prompt_select`](CliPrompt::prompt_select) does.
+    fn format_radio_option(&self, label: &str, is_active: bool) -> String {
+        if is_active {
+            self.format_bar_line(&format!(
+                "{} {}",
+                self.apply_style(&self.colors.active, &self.s_radio_active),
+                label
+            ))
+        } else {
+            self.format_bar_line(&format!("{} {}", self.s_radio_inactive, label))
+        }
+    }
+
+    fn format_hint(&self, hint: &str) -> String {
+        self.format_bar_line(&self.apply_style(&self.colors.hint, hint).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_height() {
+        let lines = vec![
+            String::new(),
+            "a".repeat(10),
+            "a".repeat(11),
+            "\x1b[31maaaaa\x1b[0m".to_string(),
+            "あ".repeat(6),
+        ];
+
+        assert_eq!(frame_height(&lines, 10), 1 + 1 + 2 + 1 + 2);
+        assert_eq!(frame_height(&lines, 80), lines.len());
+    }
+}
@@ -0,0 +1,319 @@
+use crate::cli_prompt_error::CliPromptError;
+use crate::line_buffer::LineBuffer;
+use crate::prompts::edit_line;
+use crate::{CliPrompt, Key, MessageType};
+
+/// Maximum number of suggestions shown at once by [`prompt_autocomplete`](CliPrompt::prompt_autocomplete).
+const MAX_SUGGESTIONS: usize = 8;
+
+/// Suggestions and Enter key behavior of [`prompt_autocomplete_with_options`](CliPrompt::prompt_autocomplete_with_options).
+///
+/// # Example
+/// ```no_run
+/// use cli_prompts_rs::{AutocompleteOptions, CliPrompt};
+///
+/// let options = AutocompleteOptions {
+///     suggestions: vec!["main".to_string(), "develop".to_string()],
+///     prefers_text: true,
+/// };
+///
+/// let mut cli_prompt = CliPrompt::new();
+/// let branch = cli_prompt
+///     .prompt_autocomplete_with_options("Branch", options)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AutocompleteOptions {
+    /// Values listed below the input. Those containing the input are listed, ignoring case.
+    pub suggestions: Vec<String>,
+    /// Highlights no suggestion at first, so that Enter key accepts the typed text
+    /// unless users highlight a suggestion. Otherwise the first matching suggestion is highlighted.
+    pub prefers_text: bool,
+}
+
+impl AutocompleteOptions {
+    fn matching_suggestions(&self, input: &str) -> Vec<&str> {
+        let input = input.trim().to_lowercase();
+        self.suggestions
+            .iter()
+            .filter(|suggestion| suggestion.to_lowercase().contains(&input))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the suggestion highlighted at first for the input.
+    fn initial_highlight(&self, input: &str) -> Option<usize> {
+        if self.prefers_text || self.matching_suggestions(input).is_empty() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl CliPrompt {
+    /// Prints the prompt message and let users type a value with a filtered list of suggestions below it.
+    /// Users can highlight a suggestion by Arrow Up and Arrow Down key and fill it into the input by Tab key.
+    /// Enter key accepts the highlighted suggestion, or the typed text if no suggestion is highlighted.
+    /// The first matching suggestion is highlighted while typing.
+    ///
+    /// Returns the accepted value as `String` wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_autocomplete_with_options`] with `prefers_text` of `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `suggestions` - the list of known values
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let branches = vec!["main".to_string(), "develop".to_string(), "release/1.0".to_string()];
+    /// let branch = cli_prompt.prompt_autocomplete("Branch", branches).unwrap();
+    /// println!("{}", branch);
+    /// ```
+    pub fn prompt_autocomplete(
+        &mut self,
+        message: &str,
+        suggestions: Vec<String>,
+    ) -> Result<String, CliPromptError> {
+        self.prompt_autocomplete_with_options(
+            message,
+            AutocompleteOptions {
+                suggestions,
+                ..AutocompleteOptions::default()
+            },
+        )
+    }
+
+    /// Prints the prompt message and let users type a value with a filtered list of suggestions below it.
+    /// With [`AutocompleteOptions::prefers_text`], no suggestion is highlighted while typing,
+    /// so Enter key accepts the typed text unless users highlight a suggestion.
+    /// The other keys are same as [`CliPrompt::prompt_autocomplete`].
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `options` - the suggestions and the Enter key behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{AutocompleteOptions, CliPrompt};
+    ///
+    /// let options = AutocompleteOptions {
+    ///     suggestions: vec!["main".to_string(), "develop".to_string()],
+    ///     prefers_text: true,
+    /// };
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let branch = cli_prompt
+    ///     .prompt_autocomplete_with_options("New or existing branch", options)
+    ///     .unwrap();
+    /// println!("{}", branch);
+    /// ```
+    pub fn prompt_autocomplete_with_options(
+        &mut self,
+        message: &str,
+        options: AutocompleteOptions,
+    ) -> Result<String, CliPromptError> {
+        let mut buffer = LineBuffer::default();
+        let mut highlight = options.initial_highlight("");
+
+        self.term.hide_cursor()?;
+        let frame = self.autocomplete_frame(message, &buffer, &options, highlight, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            let input = buffer.text();
+            let len = options.matching_suggestions(&input).len();
+
+            match self.term.read_key()? {
+                Key::Enter => {
+                    if let Some(i) = highlight {
+                        buffer = LineBuffer::new(options.matching_suggestions(&input)[i]);
+                    }
+                    break;
+                }
+                Key::ArrowDown if len > 0 => {
+                    highlight = Some(highlight.map_or(0, |i| (i + 1) % len));
+                }
+                Key::ArrowUp if len > 0 => {
+                    highlight = match highlight {
+                        Some(0) => None,
+                        Some(i) => Some(i - 1),
+                        None => Some(len - 1),
+                    };
+                }
+                Key::Tab if len > 0 => {
+                    let suggestion = options.matching_suggestions(&input)[highlight.unwrap_or(0)];
+                    buffer = LineBuffer::new(suggestion);
+                    highlight = options.initial_highlight(suggestion);
+                }
+                key => {
+                    if !edit_line(&mut buffer, &key) {
+                        continue;
+                    }
+                    highlight = options.initial_highlight(&buffer.text());
+                }
+            }
+
+            let frame = self.autocomplete_frame(message, &buffer, &options, highlight, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.autocomplete_frame(message, &buffer, &options, None, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(buffer.text().trim().to_string())
+    }
+
+    /// Builds the frame of the autocomplete prompt. Once submitted, only the accepted value is drawn.
+    fn autocomplete_frame(
+        &self,
+        message: &str,
+        buffer: &LineBuffer,
+        options: &AutocompleteOptions,
+        highlight: Option<usize>,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            return vec![question, self.format_bar_line(buffer.text().trim())];
+        }
+
+        let mut frame = vec![
+            question,
+            self.format_bar_line(&self.format_cursor_line(buffer)),
+        ];
+
+        let suggestions = options.matching_suggestions(&buffer.text());
+        let start = (highlight.unwrap_or(0) + 1).saturating_sub(MAX_SUGGESTIONS);
+        for (i, suggestion) in suggestions
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MAX_SUGGESTIONS)
+        {
+            frame.push(self.format_radio_option(suggestion, highlight == Some(i)));
+        }
+        if suggestions.len() > MAX_SUGGESTIONS {
+            frame.push(self.format_hint(&format!(
+                "({}/{} suggestions)",
+                highlight.map_or(0, |i| i + 1),
+                suggestions.len()
+            )));
+        }
+
+        frame.push(self.format_hint(if highlight.is_some() {
+            "up/down to choose, tab to complete, enter to accept the suggestion"
+        } else {
+            "up/down to choose, tab to complete, enter to accept the text"
+        }));
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_suggestions() -> Vec<String> {
+        vec![
+            "main".to_string(),
+            "feature/login".to_string(),
+            "feature/logout".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_prompt_autocomplete() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["L", "O", "G", "arrow down", "enter"]);
+
+        let result = cli_prompt
+            .prompt_autocomplete("message", build_suggestions())
+            .unwrap();
+
+        assert_eq!(result, "feature/logout");
+        assert_eq!(
+            format!(
+                "{} message\n{} feature/logout\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_autocomplete_raw_text() {
+        let mut cli_prompt = CliPrompt::new();
+        // moving up from the first suggestion highlights nothing
+        cli_prompt.push_key_inputs(&["m", "a", "arrow up", "enter"]);
+
+        let result = cli_prompt
+            .prompt_autocomplete("message", build_suggestions())
+            .unwrap();
+
+        assert_eq!(result, "ma");
+    }
+
+    #[test]
+    fn test_prompt_autocomplete_prefers_text() {
+        let mut cli_prompt = CliPrompt::new();
+        let options = AutocompleteOptions {
+            suggestions: build_suggestions(),
+            prefers_text: true,
+        };
+        cli_prompt.push_key_inputs(&["f", "tab", "i", "x", "enter"]);
+
+        let result = cli_prompt
+            .prompt_autocomplete_with_options("message", options)
+            .unwrap();
+
+        assert_eq!(result, "feature/loginix");
+    }
+
+    #[test]
+    fn test_autocomplete_frame() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let options = AutocompleteOptions {
+            suggestions: build_suggestions(),
+            prefers_text: false,
+        };
+
+        let frame = cli_prompt.autocomplete_frame(
+            "message",
+            &LineBuffer::new("out"),
+            &options,
+            options.initial_highlight("out"),
+            true,
+        );
+
+        assert_eq!(frame.len(), 4);
+        assert_eq!(
+            frame[2],
+            format!(
+                "{} {} feature/logout",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_radio_active").unwrap()
+            )
+        );
+    }
+}
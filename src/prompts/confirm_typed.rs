@@ -0,0 +1,197 @@
+use crate::cli_prompt_error::CliPromptError;
+use crate::color::Style;
+use crate::line_buffer::LineBuffer;
+use crate::prompts::edit_line;
+use crate::{CliPrompt, Key, MessageType};
+
+/// State of the typed-confirmation frame.
+enum TypedState {
+    Editing,
+    Submitted,
+    Cancelled,
+}
+
+impl CliPrompt {
+    /// Prints the prompt message and let users type the `expected` phrase to confirm,
+    /// like GitHub asks for the repository name before deleting it.
+    /// Every typed character is colored green if it matches the phrase and red otherwise.
+    /// Enter submits only when the input is exactly same as `expected`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `expected` - the phrase users have to type
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CliPromptError::Cancelled`] if users press Escape key.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// match cli_prompt.prompt_confirm_typed("Delete the environment?", "staging") {
+    ///     Ok(()) => println!("deleting"),
+    ///     Err(_) => println!("aborted"),
+    /// }
+    /// ```
+    pub fn prompt_confirm_typed(
+        &mut self,
+        message: &str,
+        expected: &str,
+    ) -> Result<(), CliPromptError> {
+        let mut buffer = LineBuffer::new("");
+
+        self.term.hide_cursor()?;
+        let frame = self.confirm_typed_frame(message, expected, &buffer, TypedState::Editing);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        let state = loop {
+            match self.term.read_key()? {
+                Key::Enter if buffer.text() == expected => break TypedState::Submitted,
+                Key::Escape => break TypedState::Cancelled,
+                key => {
+                    if !edit_line(&mut buffer, &key) {
+                        continue;
+                    }
+                }
+            }
+
+            let frame = self.confirm_typed_frame(message, expected, &buffer, TypedState::Editing);
+            height = self.draw_frame(&frame, height)?;
+        };
+
+        let is_cancelled = matches!(state, TypedState::Cancelled);
+        let frame = self.confirm_typed_frame(message, expected, &buffer, state);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        if is_cancelled {
+            return Err(CliPromptError::Cancelled);
+        }
+
+        Ok(())
+    }
+
+    /// Builds the frame of the typed-confirmation prompt.
+    /// Once submitted or cancelled, only the result is drawn.
+    fn confirm_typed_frame(
+        &self,
+        message: &str,
+        expected: &str,
+        buffer: &LineBuffer,
+        state: TypedState,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        match state {
+            TypedState::Submitted => vec![question, self.format_bar_line(expected)],
+            TypedState::Cancelled => vec![
+                question,
+                self.format_bar_line(
+                    &self
+                        .apply_style(&self.colors.cancel, "Cancelled")
+                        .to_string(),
+                ),
+            ],
+            TypedState::Editing => vec![
+                question,
+                self.format_hint(&format!("Type \"{}\" to confirm", expected)),
+                self.format_bar_line(&self.format_typed_input(expected, buffer)),
+                self.format_hint("enter to confirm, esc to cancel"),
+            ],
+        }
+    }
+
+    /// Colors each character by whether it matches `expected` at the same position,
+    /// drawing the cursor in reverse video.
+    fn format_typed_input(&self, expected: &str, buffer: &LineBuffer) -> String {
+        let mut expected_chars = expected.chars();
+        let mut line = String::new();
+
+        for (index, c) in buffer.text().chars().enumerate() {
+            let mut style = if expected_chars.next() == Some(c) {
+                self.colors.success
+            } else {
+                self.colors.error
+            };
+            if index == buffer.cursor() {
+                style = style.reverse();
+            }
+
+            line.push_str(&self.apply_style(&style, c).to_string());
+        }
+
+        if buffer.cursor() == buffer.text().chars().count() {
+            line.push_str(&self.apply_style(&Style::new().reverse(), ' ').to_string());
+        }
+
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli_prompt_error::CliPromptError;
+    use crate::tests::build_prefix_map;
+    use crate::CliPrompt;
+    use console::style;
+
+    #[test]
+    fn test_prompt_confirm_typed() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["p", "r", "o", "d", "enter"]);
+
+        cli_prompt.prompt_confirm_typed("message", "prod").unwrap();
+
+        assert_eq!(
+            format!(
+                "{} message\n{} prod\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_confirm_typed_requires_exact_match() {
+        let mut cli_prompt = CliPrompt::new();
+        // Enter on a mismatching input is ignored until the typo is fixed
+        cli_prompt.push_key_inputs(&["p", "o", "enter", "backspace", "r", "o", "d", "enter"]);
+
+        assert!(cli_prompt.prompt_confirm_typed("message", "prod").is_ok());
+    }
+
+    #[test]
+    fn test_prompt_confirm_typed_cancelled() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["p", "escape"]);
+
+        assert!(matches!(
+            cli_prompt.prompt_confirm_typed("message", "prod"),
+            Err(CliPromptError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_format_typed_input() {
+        let cli_prompt = CliPrompt::new();
+        let buffer = crate::line_buffer::LineBuffer::new("pa");
+
+        assert_eq!(
+            cli_prompt.format_typed_input("prod", &buffer),
+            format!(
+                "{}{}{}",
+                style('p').green(),
+                style('a').red(),
+                style(' ').reverse()
+            )
+        );
+    }
+}
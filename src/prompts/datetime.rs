@@ -0,0 +1,453 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidDateRangeError};
+use crate::color::Style;
+use crate::datetime::{Date, Time};
+use crate::{CliPrompt, Key, MessageType};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Returns the date within the bounds.
+fn clamp_date(date: Date, min: Option<Date>, max: Option<Date>) -> Date {
+    let date = min.map_or(date, |min| date.max(min));
+
+    max.map_or(date, |max| date.min(max))
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum TimeField {
+    Hour,
+    Minute,
+}
+
+impl CliPrompt {
+    /// Prints the prompt message with a calendar of the month and let users pick a date, starting from today.
+    /// Users can move the selection by Arrow Left and Arrow Right key by a day,
+    /// by Arrow Up and Arrow Down key by a week, by Page Up and Page Down key by a month,
+    /// and choose the selection by Enter key.
+    ///
+    /// Returns the picked date as [`Date`] wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_date_with_bounds`] with today and no bounds.
+    /// Today is the date in UTC, see [`Date::today`].
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let date = cli_prompt.prompt_date("Maintenance date").unwrap();
+    /// println!("{}", date);
+    /// ```
+    pub fn prompt_date(&mut self, message: &str) -> Result<Date, CliPromptError> {
+        self.prompt_date_with_bounds(message, Date::today(), None, None)
+    }
+
+    /// Prints the prompt message with a calendar of the month and let users pick a date between `min` and `max`.
+    /// The days out of the bounds are dimmed and can't be selected.
+    /// The keys are same as [`CliPrompt::prompt_date`].
+    ///
+    /// Returns the picked date as [`Date`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `initial` - the date selected at first. It is moved into the bounds if it is out of them.
+    /// * `min` - the earliest date, inclusive
+    /// * `max` - the latest date, inclusive
+    ///
+    /// # Errors
+    ///
+    /// If `min` is later than `max`, [`InvalidDateRangeError`](InvalidDateRangeError) will be returned.
+    ///
+    /// ```
+    /// use cli_prompts_rs::datetime::Date;
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let result = cli_prompt.prompt_date_with_bounds(
+    ///     "message",
+    ///     Date::today(),
+    ///     Date::new(2024, 2, 1),
+    ///     Date::new(2024, 1, 1),
+    /// );
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().to_string(), "min must not be later than max");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::datetime::Date;
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let today = Date::today();
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let date = cli_prompt
+    ///     .prompt_date_with_bounds("Maintenance date", today, Some(today), Some(today.add_months(3)))
+    ///     .unwrap();
+    /// println!("{}", date);
+    /// ```
+    pub fn prompt_date_with_bounds(
+        &mut self,
+        message: &str,
+        initial: Date,
+        min: Option<Date>,
+        max: Option<Date>,
+    ) -> Result<Date, CliPromptError> {
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(InvalidDateRangeError {
+                    message: "min must not be later than max".to_string(),
+                });
+            }
+        }
+
+        let mut date = clamp_date(initial, min, max);
+
+        self.term.hide_cursor()?;
+        let frame = self.date_frame(message, date, min, max, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            let moved = match self.term.read_key()? {
+                Key::Enter => break,
+                Key::ArrowLeft => date.add_days(-1),
+                Key::ArrowRight => date.add_days(1),
+                Key::ArrowUp => date.add_days(-7),
+                Key::ArrowDown => date.add_days(7),
+                Key::PageUp => date.add_months(-1),
+                Key::PageDown => date.add_months(1),
+                _ => continue,
+            };
+            date = clamp_date(moved, min, max);
+
+            let frame = self.date_frame(message, date, min, max, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.date_frame(message, date, min, max, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(date)
+    }
+
+    /// Prints the prompt message with hour and minute spinners and let users pick a time, starting from 00:00.
+    /// Users can change the focused field by Arrow Up and Arrow Down key,
+    /// switch the field by Arrow Left, Arrow Right and Tab key,
+    /// and choose the time by Enter key.
+    ///
+    /// Returns the picked time as [`Time`] wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_time_with_initial`] with 00:00.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let time = cli_prompt.prompt_time("Start time").unwrap();
+    /// println!("{}", time);
+    /// ```
+    pub fn prompt_time(&mut self, message: &str) -> Result<Time, CliPromptError> {
+        self.prompt_time_with_initial(message, Time::default())
+    }
+
+    /// Prints the prompt message with hour and minute spinners starting from `initial`, and let users pick a time.
+    /// The keys are same as [`CliPrompt::prompt_time`].
+    ///
+    /// Returns the picked time as [`Time`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `initial` - the time shown at first
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::datetime::Time;
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let time = cli_prompt
+    ///     .prompt_time_with_initial("Start time", Time::new(22, 0).unwrap())
+    ///     .unwrap();
+    /// println!("{}", time);
+    /// ```
+    pub fn prompt_time_with_initial(
+        &mut self,
+        message: &str,
+        initial: Time,
+    ) -> Result<Time, CliPromptError> {
+        let mut hour = initial.hour();
+        let mut minute = initial.minute();
+        let mut field = TimeField::Hour;
+
+        self.term.hide_cursor()?;
+        let frame = self.time_frame(message, hour, minute, Some(field));
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match (self.term.read_key()?, field) {
+                (Key::Enter, _) => break,
+                (Key::ArrowUp, TimeField::Hour) => hour = (hour + 1) % 24,
+                (Key::ArrowDown, TimeField::Hour) => hour = (hour + 23) % 24,
+                (Key::ArrowUp, TimeField::Minute) => minute = (minute + 1) % 60,
+                (Key::ArrowDown, TimeField::Minute) => minute = (minute + 59) % 60,
+                (Key::ArrowLeft | Key::ArrowRight | Key::Tab | Key::BackTab, _) => {
+                    field = match field {
+                        TimeField::Hour => TimeField::Minute,
+                        TimeField::Minute => TimeField::Hour,
+                    };
+                }
+                _ => continue,
+            }
+
+            let frame = self.time_frame(message, hour, minute, Some(field));
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.time_frame(message, hour, minute, None);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(Time::new(hour, minute).expect("hour and minute are wrapped into range"))
+    }
+
+    /// Builds the frame of the date prompt. The calendar is drawn only while `is_editing`.
+    fn date_frame(
+        &self,
+        message: &str,
+        date: Date,
+        min: Option<Date>,
+        max: Option<Date>,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let mut frame = vec![self.format_prefix(message.to_string(), MessageType::Question)];
+
+        if !is_editing {
+            frame.push(self.format_bar_line(&date.to_string()));
+            return frame;
+        }
+
+        frame.push(self.format_bar_line(&format!(
+            "{} {}",
+            MONTH_NAMES[date.month() as usize - 1],
+            date.year()
+        )));
+        frame.push(
+            self.format_bar_line(
+                &self
+                    .apply_style(&self.colors.hint, "Su Mo Tu We Th Fr Sa")
+                    .to_string(),
+            ),
+        );
+
+        let first = date.first_of_month();
+        let mut cells = vec!["  ".to_string(); first.weekday() as usize];
+        // the days are listed by number, since adding days saturates at the last supported date
+        for day in (1..=31).map_while(|day| Date::new(date.year(), date.month(), day)) {
+            let label = format!("{:>2}", day.day());
+            cells.push(if day == date {
+                self.apply_style(&Style::new().reverse(), label).to_string()
+            } else if day != clamp_date(day, min, max) {
+                self.apply_style(&self.colors.hint, label).to_string()
+            } else {
+                label
+            });
+        }
+        for week in cells.chunks(7) {
+            frame.push(self.format_bar_line(week.join(" ").trim_end()));
+        }
+
+        frame.push(self.format_hint("left/right day, up/down week, page up/down month"));
+
+        frame
+    }
+
+    /// Builds the frame of the time prompt. The focused field is drawn in reverse video while it is given.
+    fn time_frame(
+        &self,
+        message: &str,
+        hour: u32,
+        minute: u32,
+        field: Option<TimeField>,
+    ) -> Vec<String> {
+        let format_field = |value: u32, this: TimeField| {
+            let label = format!("{:02}", value);
+            if field == Some(this) {
+                self.apply_style(&Style::new().reverse(), label).to_string()
+            } else {
+                label
+            }
+        };
+        let mut frame = vec![
+            self.format_prefix(message.to_string(), MessageType::Question),
+            self.format_bar_line(&format!(
+                "{}:{}",
+                format_field(hour, TimeField::Hour),
+                format_field(minute, TimeField::Minute)
+            )),
+        ];
+
+        if field.is_some() {
+            frame.push(self.format_hint("up/down to change, left/right to switch field"));
+        }
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_prompt_date_keys() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&[
+            "arrow right",
+            "arrow down",
+            "page down",
+            "arrow up",
+            "arrow left",
+            "enter",
+        ]);
+
+        let result = cli_prompt
+            .prompt_date_with_bounds("message", date(2024, 1, 31), None, None)
+            .unwrap();
+
+        // 01-31 -> 02-01 -> 02-08 -> 03-08 -> 03-01 -> 02-29
+        assert_eq!(result, date(2024, 2, 29));
+    }
+
+    #[test]
+    fn test_prompt_date_keys_at_the_last_year() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["page down", "arrow down", "arrow down", "enter"]);
+
+        let result = cli_prompt
+            .prompt_date_with_bounds("message", date(i32::MAX, 12, 20), None, None)
+            .unwrap();
+
+        assert_eq!(result, date(i32::MAX, 12, 31));
+    }
+
+    #[test]
+    fn test_prompt_date_bounds() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["page up", "arrow left", "enter"]);
+
+        let result = cli_prompt
+            .prompt_date_with_bounds(
+                "message",
+                date(2024, 3, 20),
+                Some(date(2024, 3, 10)),
+                Some(date(2024, 3, 25)),
+            )
+            .unwrap();
+
+        assert_eq!(result, date(2024, 3, 10));
+
+        cli_prompt.push_key_inputs(&["enter"]);
+
+        let result = cli_prompt
+            .prompt_date_with_bounds("message", date(2024, 1, 1), Some(date(2024, 3, 10)), None)
+            .unwrap();
+
+        assert_eq!(result, date(2024, 3, 10));
+
+        let result = cli_prompt.prompt_date_with_bounds(
+            "message",
+            date(2024, 1, 1),
+            Some(date(2024, 3, 10)),
+            Some(date(2024, 3, 9)),
+        );
+
+        assert!(matches!(result, Err(InvalidDateRangeError { .. })));
+    }
+
+    #[test]
+    fn test_date_frame() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let bar = cli_prompt.s_bar.clone();
+
+        let frame = cli_prompt.date_frame("message", date(2024, 2, 13), None, None, true);
+
+        assert_eq!(
+            frame[1..8],
+            [
+                format!("{} February 2024", bar),
+                format!("{} Su Mo Tu We Th Fr Sa", bar),
+                format!("{} {} 1  2  3", bar, " ".repeat(12)),
+                format!("{}  4  5  6  7  8  9 10", bar),
+                format!("{} 11 12 13 14 15 16 17", bar),
+                format!("{} 18 19 20 21 22 23 24", bar),
+                format!("{} 25 26 27 28 29", bar),
+            ]
+        );
+
+        let frame = cli_prompt.date_frame("message", date(2024, 2, 13), None, None, false);
+
+        assert_eq!(frame[1], format!("{} 2024-02-13", bar));
+    }
+
+    #[test]
+    fn test_prompt_time() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&[
+            "arrow down",
+            "arrow right",
+            "arrow down",
+            "arrow down",
+            "tab",
+            "arrow up",
+            "enter",
+        ]);
+
+        let result = cli_prompt.prompt_time("message").unwrap();
+
+        assert_eq!(result, Time::new(0, 58).unwrap());
+        assert!(cli_prompt
+            .term
+            .get_output_string()
+            .contains(&format!("{} 00:58\n", cli_prompt.s_bar)));
+    }
+}
@@ -0,0 +1,322 @@
+use crate::cli_prompt_error::CliPromptError::{self, EditorError};
+use crate::{CliPrompt, Key, MessageType};
+use std::collections::hash_map::RandomState;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const DEFAULT_EDITOR: &str = "vi";
+
+/// Returns the editor command split into the program and its arguments.
+/// `$VISUAL` takes precedence over `$EDITOR`, and the platform default is used when neither is set.
+fn editor_command<F>(var: F) -> Vec<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|key| var(key))
+        .map(|value| {
+            value
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<String>>()
+        })
+        .find(|command| !command.is_empty())
+        .unwrap_or_else(|| vec![DEFAULT_EDITOR.to_string()])
+}
+
+fn temp_file_path(extension: &str) -> PathBuf {
+    // `RandomState` is seeded randomly, so the name can't be guessed by other users
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default(),
+    );
+    let extension = extension.trim_start_matches('.');
+    let file_name = if extension.is_empty() {
+        format!(
+            "cli_prompts_rs-{}-{:016x}",
+            std::process::id(),
+            hasher.finish()
+        )
+    } else {
+        format!(
+            "cli_prompts_rs-{}-{:016x}.{}",
+            std::process::id(),
+            hasher.finish(),
+            extension
+        )
+    };
+
+    env::temp_dir().join(file_name)
+}
+
+/// Creates a new temp file with the content, failing instead of reusing a file which already exists.
+fn create_temp_file(content: &str, extension: &str) -> io::Result<PathBuf> {
+    loop {
+        let path = temp_file_path(extension);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                if let Err(error) = file.write_all(content.as_bytes()) {
+                    let _ = fs::remove_file(&path);
+                    return Err(error);
+                }
+                return Ok(path);
+            }
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+fn run_editor(content: &str, extension: &str) -> Result<String, CliPromptError> {
+    let path = create_temp_file(content, extension)?;
+
+    let command = editor_command(|key| env::var(key).ok());
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .arg(&path)
+        .status();
+    let result = match status {
+        Ok(status) if status.success() => fs::read_to_string(&path).map_err(CliPromptError::from),
+        Ok(status) => Err(EditorError {
+            message: format!("editor `{}` exited with {}", command[0], status),
+        }),
+        Err(error) => Err(EditorError {
+            message: format!("failed to launch editor `{}`: {}", command[0], error),
+        }),
+    };
+    let _ = fs::remove_file(&path);
+
+    result.map(|edited| match edited.strip_suffix('\n') {
+        Some(edited) => edited.strip_suffix('\r').unwrap_or(edited).to_string(),
+        None => edited,
+    })
+}
+
+impl CliPrompt {
+    /// Prints the prompt message and opens the external editor when users press Enter key.
+    /// The editor is taken from `$VISUAL`, then `$EDITOR`, and falls back to `vi` (`notepad` on Windows).
+    ///
+    /// Returns the edited content as `String` wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_editor_with_content`] with empty content and `txt` extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let description = cli_prompt.prompt_editor("Describe the change").unwrap();
+    /// println!("{}", description);
+    /// ```
+    pub fn prompt_editor(&mut self, message: &str) -> Result<String, CliPromptError> {
+        self.prompt_editor_with_content(message, "", "txt")
+    }
+
+    /// Prints the prompt message and opens the external editor with `content` when users press Enter key.
+    /// The content is written to a temp file with the given `extension`, so that editors can highlight it.
+    ///
+    /// Returns the edited content as `String` wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `content` - the initial content of the editor
+    /// * `extension` - the extension of the temp file, like `md`
+    ///
+    /// # Errors
+    ///
+    /// If the editor can't be launched or exits with failure, [`EditorError`](EditorError) will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let release_note = cli_prompt
+    ///     .prompt_editor_with_content("Release note", "## Added\n", "md")
+    ///     .unwrap();
+    /// println!("{}", release_note);
+    /// ```
+    pub fn prompt_editor_with_content(
+        &mut self,
+        message: &str,
+        content: &str,
+        extension: &str,
+    ) -> Result<String, CliPromptError> {
+        self.term.hide_cursor()?;
+        let frame = vec![
+            self.format_prefix(message.to_string(), MessageType::Question),
+            self.format_hint("Press Enter to open editor"),
+        ];
+        let height = self.draw_frame(&frame, 0)?;
+
+        // keys can't be read without a terminal, so the editor is opened right away
+        if self.term.is_term() {
+            while self.term.read_key()? != Key::Enter {}
+        }
+
+        let edited = run_editor(content, extension);
+        self.term.show_cursor()?;
+        let edited = edited?;
+
+        let line_count = edited.lines().count();
+        let summary = match line_count {
+            0 => String::new(),
+            1 => edited.clone(),
+            _ => format!(
+                "{} {}",
+                edited.lines().next().unwrap_or_default(),
+                self.apply_style(
+                    &self.colors.hint,
+                    match line_count - 1 {
+                        1 => "(+1 line)".to_string(),
+                        more => format!("(+{} lines)", more),
+                    }
+                )
+            ),
+        };
+        let frame = vec![
+            self.format_prefix(message.to_string(), MessageType::Question),
+            self.format_bar_line(&summary),
+        ];
+        self.draw_frame(&frame, height)?;
+        self.print_empty_line()?;
+
+        Ok(edited)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    #[test]
+    fn test_editor_command() {
+        let var = |visual: Option<&str>, editor: Option<&str>| {
+            let visual = visual.map(str::to_string);
+            let editor = editor.map(str::to_string);
+            editor_command(move |key| match key {
+                "VISUAL" => visual.clone(),
+                "EDITOR" => editor.clone(),
+                _ => None,
+            })
+        };
+
+        assert_eq!(
+            var(Some("code --wait"), Some("vim")),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(var(None, Some("vim")), vec!["vim"]);
+        assert_eq!(var(Some(" "), Some("nano")), vec!["nano"]);
+        assert_eq!(var(None, None), vec![DEFAULT_EDITOR]);
+    }
+
+    #[test]
+    fn test_temp_file_path() {
+        assert!(temp_file_path("md").to_string_lossy().ends_with(".md"));
+        assert!(temp_file_path(".rs").to_string_lossy().ends_with(".rs"));
+        assert!(temp_file_path("").extension().is_none());
+        assert_ne!(temp_file_path("md"), temp_file_path("md"));
+    }
+
+    #[test]
+    fn test_create_temp_file() {
+        let path = create_temp_file("content", "md").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "content");
+        fs::remove_file(&path).unwrap();
+    }
+
+    // Environment variables are shared between threads, so every case touching `EDITOR` stays in this test.
+    #[cfg(unix)]
+    #[test]
+    fn test_prompt_editor_with_script() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let prefix_map = build_prefix_map();
+        let script =
+            env::temp_dir().join(format!("cli_prompts_rs-editor-{}.sh", std::process::id()));
+        fs::write(
+            &script,
+            "#!/bin/sh\ncase \"$1\" in *.md) ;; *) exit 1 ;; esac\nprintf 'edited\\n' >> \"$1\"\n",
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        env::remove_var("VISUAL");
+        env::set_var("EDITOR", &script);
+
+        let mut cli_prompt = CliPrompt::new();
+        let result = cli_prompt
+            .prompt_editor_with_content("message", "initial\n", "md")
+            .unwrap();
+
+        assert_eq!(result, "initial\nedited");
+        assert_eq!(
+            format!(
+                "{} message\n{} initial (+1 line)\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt
+            .prompt_editor_with_content("message", "first\nsecond\n", "md")
+            .unwrap();
+
+        assert!(cli_prompt
+            .term
+            .get_output_string()
+            .contains("first (+2 lines)"));
+
+        // without a terminal, the editor is opened without reading keys
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.term.is_term = false;
+        cli_prompt.push_key_input("a");
+        let result = cli_prompt
+            .prompt_editor_with_content("message", "", "md")
+            .unwrap();
+
+        assert_eq!(result, "edited");
+        assert_eq!(cli_prompt.term.key_input.len(), 1);
+
+        let mut cli_prompt = CliPrompt::new();
+        let result = cli_prompt.prompt_editor_with_content("message", "", "txt");
+
+        assert!(matches!(result, Err(EditorError { .. })));
+
+        env::set_var("EDITOR", "cli-prompts-rs-missing-editor");
+        let mut cli_prompt = CliPrompt::new();
+        let result = cli_prompt.prompt_editor("message");
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("failed to launch editor `cli-prompts-rs-missing-editor`"));
+
+        env::remove_var("EDITOR");
+        fs::remove_file(&script).unwrap();
+    }
+}
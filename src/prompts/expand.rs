@@ -0,0 +1,242 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidHotkeyError, OptionsVecEmptyError};
+use crate::{CliPrompt, Key, MessageType, PromptSelectOption};
+
+/// Hotkey which expands the compact form of [`prompt_expand`](CliPrompt::prompt_expand).
+const HELP_KEY: char = 'h';
+
+impl CliPrompt {
+    /// Prints the prompt message with the hotkeys of the provided options in a compact form like `[y]es/[n]o/[h]elp`,
+    /// and let users choose one of them by pressing its hotkey without Enter key.
+    /// `h` key expands the compact form to the full list of options with their hotkeys.
+    /// Hotkeys are case-insensitive.
+    ///
+    /// Returns the chosen option as instance of [`PromptSelectOption`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `choices` - the list of hotkeys with their options
+    ///
+    /// # Errors
+    ///
+    /// If `choices` is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// If a hotkey is `h`, isn't alphanumeric, or is used twice, [`InvalidHotkeyError`](InvalidHotkeyError) will be returned.
+    /// ```
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectOption};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let choices = vec![
+    ///     ('y', PromptSelectOption::new("yes", "Yes")),
+    ///     ('Y', PromptSelectOption::new("yes_all", "Yes to all")),
+    /// ];
+    ///
+    /// let result = cli_prompt.prompt_expand("Overwrite?", choices);
+    ///
+    /// assert!(result.is_err());
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.to_string(), "hotkey 'y' is used twice");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectOption};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let choices = vec![
+    ///     ('y', PromptSelectOption::new("yes", "Yes")),
+    ///     ('n', PromptSelectOption::new("no", "No")),
+    ///     ('a', PromptSelectOption::new("all", "All")),
+    ///     ('d', PromptSelectOption::new("diff", "Show the diff")),
+    ///     ('q', PromptSelectOption::new("quit", "Quit")),
+    /// ];
+    /// let choice = cli_prompt.prompt_expand("Overwrite config.toml?", choices).unwrap();
+    /// println!("{}", choice);
+    /// ```
+    pub fn prompt_expand(
+        &mut self,
+        message: &str,
+        choices: Vec<(char, PromptSelectOption)>,
+    ) -> Result<PromptSelectOption, CliPromptError> {
+        if choices.is_empty() {
+            return Err(OptionsVecEmptyError {
+                message: "choices is empty".to_string(),
+            });
+        }
+
+        let choices: Vec<(char, PromptSelectOption)> = choices
+            .into_iter()
+            .map(|(key, option)| (key.to_ascii_lowercase(), option))
+            .collect();
+        for (i, (key, _)) in choices.iter().enumerate() {
+            if !key.is_ascii_alphanumeric() || *key == HELP_KEY {
+                return Err(InvalidHotkeyError {
+                    message: format!("hotkey '{}' is not available", key),
+                });
+            }
+            if choices[..i].iter().any(|(other, _)| other == key) {
+                return Err(InvalidHotkeyError {
+                    message: format!("hotkey '{}' is used twice", key),
+                });
+            }
+        }
+
+        let mut is_expanded = false;
+
+        self.term.hide_cursor()?;
+        let frame = self.expand_frame(message, &choices, is_expanded, None);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        let choice = loop {
+            let key = match self.term.read_key()? {
+                Key::Char(c) => c.to_ascii_lowercase(),
+                _ => continue,
+            };
+
+            if let Some(choice) = choices.iter().position(|(hotkey, _)| *hotkey == key) {
+                break choice;
+            }
+            if key != HELP_KEY || is_expanded {
+                continue;
+            }
+            is_expanded = true;
+
+            let frame = self.expand_frame(message, &choices, is_expanded, None);
+            height = self.draw_frame(&frame, height)?;
+        };
+
+        let frame = self.expand_frame(message, &choices, is_expanded, Some(choice));
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(choices[choice].1.clone())
+    }
+
+    /// Builds the frame of the expand prompt, either in the compact form or the full list.
+    /// Once `choice` is given, only its label is drawn.
+    fn expand_frame(
+        &self,
+        message: &str,
+        choices: &[(char, PromptSelectOption)],
+        is_expanded: bool,
+        choice: Option<usize>,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if let Some(choice) = choice {
+            return vec![question, self.format_bar_line(&choices[choice].1.label)];
+        }
+
+        if !is_expanded {
+            let mut labels: Vec<String> = choices
+                .iter()
+                .map(|(key, option)| self.format_compact_choice(*key, &option.label))
+                .collect();
+            labels.push(self.format_compact_choice(HELP_KEY, "help"));
+
+            return vec![question, self.format_bar_line(&labels.join("/"))];
+        }
+
+        let mut frame = vec![question];
+        for (key, option) in choices {
+            frame.push(self.format_bar_line(&format!(
+                "{} {}",
+                self.apply_style(&self.colors.active, key),
+                option.label
+            )));
+        }
+        frame.push(self.format_hint("press a key to choose"));
+
+        frame
+    }
+
+    /// Formats the choice like `[y]es` if the label starts with the hotkey, otherwise like `[d] Show the diff`.
+    fn format_compact_choice(&self, key: char, label: &str) -> String {
+        let hotkey = self.apply_style(&self.colors.active, key);
+        let mut chars = label.chars();
+
+        match chars.next() {
+            Some(first) if first.to_ascii_lowercase() == key => {
+                format!("[{}]{}", hotkey, chars.as_str())
+            }
+            _ => format!("[{}] {}", hotkey, label),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_choices() -> Vec<(char, PromptSelectOption)> {
+        vec![
+            ('y', PromptSelectOption::new("yes", "Yes")),
+            ('n', PromptSelectOption::new("no", "No")),
+            ('d', PromptSelectOption::new("diff", "Show the diff")),
+        ]
+    }
+
+    #[test]
+    fn test_expand_frame() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let bar = prefix_map.get("s_bar").unwrap();
+
+        let frame = cli_prompt.expand_frame("message", &build_choices(), false, None);
+
+        assert_eq!(
+            frame[1],
+            format!("{} [y]es/[n]o/[d] Show the diff/[h]elp", bar)
+        );
+
+        let frame = cli_prompt.expand_frame("message", &build_choices(), true, None);
+
+        assert_eq!(frame.len(), 5);
+        assert_eq!(frame[3], format!("{} d Show the diff", bar));
+    }
+
+    #[test]
+    fn test_prompt_expand() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["x", "enter", "h", "D"]);
+
+        let result = cli_prompt
+            .prompt_expand("message", build_choices())
+            .unwrap();
+
+        assert_eq!(result.value, "diff");
+        assert_eq!(
+            format!(
+                "{} message\n{} Show the diff\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_expand_invalid() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_expand("message", vec![]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+        assert!(matches!(
+            cli_prompt.prompt_expand(
+                "message",
+                vec![('h', PromptSelectOption::new("help", "Help"))]
+            ),
+            Err(InvalidHotkeyError { .. })
+        ));
+    }
+}
@@ -0,0 +1,629 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidFormError, OptionsVecEmptyError};
+use crate::line_buffer::LineBuffer;
+use crate::prompts::{edit_line, mask, Validator};
+use crate::{CliPrompt, Key, MessageType};
+use console::measure_text_width;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The kind of a [`FormField`], which decides how it is edited and which [`FormValue`] it returns.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FormFieldKind {
+    /// Free text, returned as [`FormValue::Text`]
+    Text,
+    /// Text printed as the password mask symbol, returned as [`FormValue::Text`]
+    Password,
+    /// Integer, returned as [`FormValue::Number`]
+    Number,
+    /// Choice between two labels, returned as [`FormValue::Bool`] which is `true` for `active`
+    Toggle { active: String, inactive: String },
+}
+
+/// A value returned by [`prompt_form`](CliPrompt::prompt_form).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FormValue {
+    Text(String),
+    Number(i64),
+    Bool(bool),
+}
+
+impl FormValue {
+    /// Returns the text of [`FormValue::Text`], `None` otherwise.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FormValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of [`FormValue::Number`], `None` otherwise.
+    pub fn as_number(&self) -> Option<i64> {
+        match self {
+            FormValue::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of [`FormValue::Bool`], `None` otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FormValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FormValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormValue::Text(text) => write!(f, "{}", text),
+            FormValue::Number(number) => write!(f, "{}", number),
+            FormValue::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<&str> for FormValue {
+    fn from(text: &str) -> Self {
+        FormValue::Text(text.to_string())
+    }
+}
+
+impl From<i64> for FormValue {
+    fn from(number: i64) -> Self {
+        FormValue::Number(number)
+    }
+}
+
+impl From<bool> for FormValue {
+    fn from(value: bool) -> Self {
+        FormValue::Bool(value)
+    }
+}
+
+/// A labeled field of [`prompt_form`](CliPrompt::prompt_form).
+///
+/// # Example
+/// ```
+/// use cli_prompts_rs::{FormField, FormFieldKind};
+///
+/// let port = FormField::number("port", "Port")
+///     .with_default(5432)
+///     .with_validator(|port| match port.parse::<u16>() {
+///         Ok(_) => Ok(()),
+///         Err(_) => Err("port must be between 0 and 65535".to_string()),
+///     });
+/// assert_eq!(port.kind, FormFieldKind::Number);
+/// ```
+pub struct FormField {
+    /// Key of the value in the result of [`prompt_form`](CliPrompt::prompt_form)
+    pub name: String,
+    pub label: String,
+    pub kind: FormFieldKind,
+    /// Value shown at first. Ignored if it doesn't fit `kind`.
+    pub default: Option<FormValue>,
+    /// Checks the text of the field, except toggles. The error message is shown below the field.
+    pub validator: Option<Validator>,
+}
+
+impl FormField {
+    pub fn new(name: &str, label: &str, kind: FormFieldKind) -> Self {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            kind,
+            default: None,
+            validator: None,
+        }
+    }
+
+    pub fn text(name: &str, label: &str) -> Self {
+        Self::new(name, label, FormFieldKind::Text)
+    }
+
+    pub fn password(name: &str, label: &str) -> Self {
+        Self::new(name, label, FormFieldKind::Password)
+    }
+
+    pub fn number(name: &str, label: &str) -> Self {
+        Self::new(name, label, FormFieldKind::Number)
+    }
+
+    pub fn toggle(name: &str, label: &str, active: &str, inactive: &str) -> Self {
+        Self::new(
+            name,
+            label,
+            FormFieldKind::Toggle {
+                active: active.to_string(),
+                inactive: inactive.to_string(),
+            },
+        )
+    }
+
+    /// Returns the field with `default` shown at first.
+    pub fn with_default<V: Into<FormValue>>(mut self, default: V) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Returns the field checked by `validator`.
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+}
+
+/// Editing state of a field.
+pub(super) struct FieldState {
+    buffer: LineBuffer,
+    /// Value of the toggle fields
+    is_active: bool,
+    pub(super) error: Option<String>,
+}
+
+impl FieldState {
+    fn new(field: &FormField) -> Self {
+        Self::with_value(field, field.default.as_ref())
+    }
+
+    /// Creates the state showing `value` at first. `value` is ignored if it doesn't fit the kind of `field`.
+    pub(super) fn with_value(field: &FormField, value: Option<&FormValue>) -> Self {
+        let text = match (&field.kind, value) {
+            (FormFieldKind::Toggle { .. }, _) | (_, None) | (_, Some(FormValue::Bool(_))) => {
+                String::new()
+            }
+            (_, Some(value)) => value.to_string(),
+        };
+
+        Self {
+            buffer: LineBuffer::new(&text),
+            is_active: value != Some(&FormValue::Bool(false)),
+            error: None,
+        }
+    }
+
+    /// Converts the field into its value, or returns the error message to show.
+    pub(super) fn value(&self, field: &FormField) -> Result<FormValue, String> {
+        if let FormFieldKind::Toggle { .. } = field.kind {
+            return Ok(FormValue::Bool(self.is_active));
+        }
+
+        let text = match field.kind {
+            FormFieldKind::Password => self.buffer.text(),
+            _ => self.buffer.text().trim().to_string(),
+        };
+        if let Some(validator) = &field.validator {
+            validator(&text)?;
+        }
+
+        match field.kind {
+            FormFieldKind::Number => text
+                .parse()
+                .map(FormValue::Number)
+                .map_err(|_| "must be a number".to_string()),
+            _ => Ok(FormValue::Text(text)),
+        }
+    }
+
+    /// Validates the field, keeping the error message to show.
+    pub(super) fn validate(&mut self, field: &FormField) -> bool {
+        self.error = self.value(field).err();
+        self.error.is_none()
+    }
+
+    /// Applies the editing key to the field. Toggle fields are switched by Arrow Left, Arrow Right and Space key.
+    ///
+    /// Returns false if the key doesn't edit the field.
+    pub(super) fn edit(&mut self, field: &FormField, key: &Key) -> bool {
+        let is_edited = match (&field.kind, key) {
+            (FormFieldKind::Toggle { .. }, Key::ArrowLeft) => {
+                self.is_active = true;
+                true
+            }
+            (FormFieldKind::Toggle { .. }, Key::ArrowRight) => {
+                self.is_active = false;
+                true
+            }
+            (FormFieldKind::Toggle { .. }, Key::Char(' ')) => {
+                self.is_active = !self.is_active;
+                true
+            }
+            (FormFieldKind::Toggle { .. }, _) => false,
+            _ => edit_line(&mut self.buffer, key),
+        };
+
+        if is_edited {
+            self.error = None;
+        }
+        is_edited
+    }
+}
+
+/// Returns [`InvalidFormError`] if a name is used twice.
+pub(super) fn check_unique_names(names: &[&str]) -> Result<(), CliPromptError> {
+    for (i, name) in names.iter().enumerate() {
+        if names[..i].contains(name) {
+            return Err(InvalidFormError {
+                message: format!("field name \"{}\" is used twice", name),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl CliPrompt {
+    /// Prints the prompt message with the provided fields at once, and let users fill them in.
+    /// Users can move between the fields by Tab and Shift+Tab key, or Arrow Down and Arrow Up key.
+    /// Text fields are edited like [`CliPrompt::prompt_text`], and toggle fields are switched by
+    /// Arrow Left, Arrow Right and Space key. Enter key submits the form if every field is valid,
+    /// otherwise the error messages are shown and the first invalid field is focused.
+    /// Each field is also validated when users leave it.
+    ///
+    /// Returns the values keyed by [`FormField::name`] as `HashMap` of [`FormValue`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `fields` - the list of fields
+    ///
+    /// # Errors
+    ///
+    /// If `fields` is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// If two fields have the same name, [`InvalidFormError`](InvalidFormError) will be returned.
+    /// ```
+    /// use cli_prompts_rs::{CliPrompt, FormField};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let fields = vec![FormField::text("host", "Host"), FormField::text("host", "Hostname")];
+    ///
+    /// let result = cli_prompt.prompt_form("Database", fields);
+    ///
+    /// assert!(result.is_err());
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.to_string(), "field name \"host\" is used twice");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, FormField};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let fields = vec![
+    ///     FormField::text("host", "Host").with_default("localhost"),
+    ///     FormField::number("port", "Port").with_default(5432),
+    ///     FormField::text("user", "User").with_validator(|user| {
+    ///         if user.is_empty() {
+    ///             Err("user is required".to_string())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }),
+    ///     FormField::password("password", "Password"),
+    ///     FormField::toggle("tls", "TLS", "On", "Off"),
+    /// ];
+    ///
+    /// let values = cli_prompt.prompt_form("Database", fields).unwrap();
+    /// let port = values["port"].as_number().unwrap();
+    /// println!("{}:{}", values["host"], port);
+    /// ```
+    pub fn prompt_form(
+        &mut self,
+        message: &str,
+        fields: Vec<FormField>,
+    ) -> Result<HashMap<String, FormValue>, CliPromptError> {
+        if fields.is_empty() {
+            return Err(OptionsVecEmptyError {
+                message: "fields is empty".to_string(),
+            });
+        }
+
+        let names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+        check_unique_names(&names)?;
+
+        let mut states: Vec<FieldState> = fields.iter().map(FieldState::new).collect();
+        let mut choice = 0;
+
+        self.term.hide_cursor()?;
+        let frame = self.form_frame(message, &fields, &states, choice, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            let key = self.term.read_key()?;
+            let state = &mut states[choice];
+
+            match key {
+                Key::Enter => {
+                    let mut first_invalid = None;
+                    for (i, (state, field)) in states.iter_mut().zip(&fields).enumerate() {
+                        if !state.validate(field) && first_invalid.is_none() {
+                            first_invalid = Some(i);
+                        }
+                    }
+
+                    match first_invalid {
+                        Some(i) => choice = i,
+                        None => break,
+                    }
+                }
+                Key::Tab | Key::ArrowDown => {
+                    state.validate(&fields[choice]);
+                    choice = (choice + 1) % fields.len();
+                }
+                Key::BackTab | Key::ArrowUp => {
+                    state.validate(&fields[choice]);
+                    choice = (choice + fields.len() - 1) % fields.len();
+                }
+                key => {
+                    if !state.edit(&fields[choice], &key) {
+                        continue;
+                    }
+                }
+            }
+
+            let frame = self.form_frame(message, &fields, &states, choice, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.form_frame(message, &fields, &states, choice, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        let mut values = HashMap::new();
+        for (field, state) in fields.iter().zip(&states) {
+            if let Ok(value) = state.value(field) {
+                values.insert(field.name.clone(), value);
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Builds the frame of the form prompt, with the labels aligned by display width.
+    /// The cursor and the error messages are drawn only while `is_editing`.
+    fn form_frame(
+        &self,
+        message: &str,
+        fields: &[FormField],
+        states: &[FieldState],
+        choice: usize,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let mut frame = vec![self.format_prefix(message.to_string(), MessageType::Question)];
+        let label_width = fields
+            .iter()
+            .map(|field| measure_text_width(&field.label))
+            .max()
+            .unwrap_or(0);
+
+        for (i, (field, state)) in fields.iter().zip(states).enumerate() {
+            let is_focused = is_editing && i == choice;
+            let label = format!(
+                "{}:{}",
+                field.label,
+                " ".repeat(label_width - measure_text_width(&field.label))
+            );
+
+            let value = self.format_field_value(field, state, is_focused, is_editing);
+
+            if !is_editing {
+                frame.push(self.format_bar_line(&format!("{} {}", label, value)));
+                continue;
+            }
+
+            frame.push(self.format_radio_option(&format!("{} {}", label, value), is_focused));
+            if let Some(error) = &state.error {
+                frame.push(self.format_bar_line(&format!(
+                    "{}  {}",
+                    " ".repeat(measure_text_width(&self.s_radio_inactive)),
+                    self.apply_style(&self.colors.error, error)
+                )));
+            }
+        }
+
+        if is_editing {
+            frame.push(self.format_hint("tab/shift+tab to move, enter to submit"));
+        }
+
+        frame
+    }
+
+    /// Formats the value of the field. Toggle fields are drawn as radio buttons while `is_editing`,
+    /// and the cursor is drawn only if `is_focused`.
+    pub(super) fn format_field_value(
+        &self,
+        field: &FormField,
+        state: &FieldState,
+        is_focused: bool,
+        is_editing: bool,
+    ) -> String {
+        match &field.kind {
+            FormFieldKind::Toggle { active, inactive } if is_editing => {
+                let radio = |is_active: bool| {
+                    if is_active {
+                        self.apply_style(&self.colors.active, &self.s_radio_active)
+                            .to_string()
+                    } else {
+                        self.s_radio_inactive.clone()
+                    }
+                };
+                format!(
+                    "{} {} / {} {}",
+                    radio(state.is_active),
+                    active,
+                    radio(!state.is_active),
+                    inactive
+                )
+            }
+            FormFieldKind::Toggle { active, inactive } => {
+                if state.is_active { active } else { inactive }.clone()
+            }
+            kind => {
+                let masked;
+                let buffer = if *kind == FormFieldKind::Password {
+                    masked = mask(&state.buffer, &self.s_password_mask);
+                    &masked
+                } else {
+                    &state.buffer
+                };
+
+                if is_focused {
+                    self.format_cursor_line(buffer)
+                } else {
+                    buffer.text()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_fields() -> Vec<FormField> {
+        vec![
+            FormField::text("host", "Host").with_default("localhost"),
+            FormField::number("port", "Port").with_default(5432),
+            FormField::password("password", "Password"),
+            FormField::toggle("tls", "TLS", "On", "Off"),
+        ]
+    }
+
+    #[test]
+    fn test_prompt_form() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&[
+            "tab",
+            "backspace",
+            "3",
+            "tab",
+            "p",
+            "w",
+            "tab",
+            "arrow right",
+            "backtab",
+            "enter",
+        ]);
+
+        let values = cli_prompt.prompt_form("message", build_fields()).unwrap();
+
+        assert_eq!(values["host"], FormValue::Text("localhost".to_string()));
+        assert_eq!(values["port"].as_number(), Some(5433));
+        assert_eq!(values["password"].as_str(), Some("pw"));
+        assert_eq!(values["tls"].as_bool(), Some(false));
+
+        let bar = prefix_map.get("s_bar").unwrap();
+        let mask = cli_prompt.s_password_mask.clone();
+        assert_eq!(
+            format!(
+                "{} message\n{} Host:     localhost\n{} Port:     5433\n{} Password: {}\n{} TLS:      Off\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                bar,
+                bar,
+                bar,
+                mask.repeat(2),
+                bar,
+                bar
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_form_validation() {
+        let mut cli_prompt = CliPrompt::new();
+        let fields = vec![
+            FormField::number("port", "Port"),
+            FormField::text("user", "User").with_validator(|user| {
+                if user.is_empty() {
+                    Err("user is required".to_string())
+                } else {
+                    Ok(())
+                }
+            }),
+        ];
+        // Enter focuses the invalid port, then the invalid user
+        cli_prompt.push_key_inputs(&[
+            "x",
+            "enter",
+            "backspace",
+            "8",
+            "enter",
+            "r",
+            "o",
+            "o",
+            "t",
+            "enter",
+        ]);
+
+        let values = cli_prompt.prompt_form("message", fields).unwrap();
+
+        assert_eq!(values["port"].as_number(), Some(8));
+        assert_eq!(values["user"].as_str(), Some("root"));
+    }
+
+    #[test]
+    fn test_form_frame_error() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let fields = build_fields();
+        let mut states: Vec<FieldState> = fields.iter().map(FieldState::new).collect();
+        states[1].buffer = LineBuffer::new("abc");
+        states[1].validate(&fields[1]);
+
+        let frame = cli_prompt.form_frame("message", &fields, &states, 0, true);
+        let bar = prefix_map.get("s_bar").unwrap();
+        let padding = " ".repeat(measure_text_width(
+            prefix_map.get("s_radio_inactive").unwrap(),
+        ));
+
+        assert_eq!(
+            frame[2],
+            format!(
+                "{} {} Port:     abc",
+                bar,
+                prefix_map.get("s_radio_inactive").unwrap()
+            )
+        );
+        assert_eq!(frame[3], format!("{} {}  must be a number", bar, padding));
+        assert_eq!(
+            frame[5],
+            format!(
+                "{} {} TLS:      {} On / {} Off",
+                bar,
+                prefix_map.get("s_radio_inactive").unwrap(),
+                prefix_map.get("s_radio_active").unwrap(),
+                prefix_map.get("s_radio_inactive").unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_form_invalid() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_form("message", vec![]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+        assert!(matches!(
+            cli_prompt.prompt_form(
+                "message",
+                vec![FormField::text("a", "A"), FormField::number("a", "B")]
+            ),
+            Err(InvalidFormError { .. })
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidAnswersError};
+use crate::prompts::wizard::WizardEnd;
+use crate::{CliPrompt, WizardAnswers, WizardStep};
+use std::fmt::Display;
+
+/// Steps of [`prompt_group`](CliPrompt::prompt_group), with the handler called when users cancel them.
+///
+/// # Example
+/// ```no_run
+/// use cli_prompts_rs::{CliPrompt, FormField, PromptGroup, WizardAnswers, WizardStep};
+///
+/// let group = PromptGroup::new(vec![
+///     WizardStep::field(FormField::text("name", "Project name")),
+///     WizardStep::field(FormField::toggle("docker", "Use Docker?", "Yes", "No")),
+///     WizardStep::field(FormField::text("image", "Base image").with_default("debian"))
+///         .with_skip_if(|answers| answers.bool("docker") == Some(false)),
+/// ])
+/// .with_on_cancel(|answers| eprintln!("Cancelled after {:?}", answers.text("name")));
+///
+/// let mut cli_prompt = CliPrompt::new();
+/// let answers: WizardAnswers = cli_prompt.prompt_group(group).unwrap();
+/// ```
+pub struct PromptGroup {
+    steps: Vec<WizardStep>,
+    on_cancel: Option<CancelHandler>,
+}
+
+/// Called with the latest answers users gave before cancelling the group.
+type CancelHandler = Box<dyn FnOnce(&WizardAnswers)>;
+
+impl PromptGroup {
+    pub fn new(steps: Vec<WizardStep>) -> Self {
+        Self {
+            steps,
+            on_cancel: None,
+        }
+    }
+
+    /// Returns the group calling `on_cancel` when users cancel it, with the latest answers of the steps
+    /// they went through, including the steps they went back from.
+    pub fn with_on_cancel<F>(mut self, on_cancel: F) -> Self
+    where
+        F: FnOnce(&WizardAnswers) + 'static,
+    {
+        self.on_cancel = Some(Box::new(on_cancel));
+        self
+    }
+}
+
+impl CliPrompt {
+    /// Prints the steps of the group one by one, and let users answer them like [`CliPrompt::prompt_wizard`].
+    /// Each step can skip itself by [`WizardStep::with_skip_if`] or choose the next step by [`WizardStep::with_next`],
+    /// depending on the answers so far, so that later questions follow earlier answers.
+    /// Ctrl+C on any step or Escape key on the first step cancels the whole group, calling the handler of [`PromptGroup::with_on_cancel`].
+    ///
+    /// Returns the answers converted into `T` wrapped in `Result`.
+    /// `T` can be [`WizardAnswers`] itself, or any type implementing `TryFrom<WizardAnswers>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - the steps and the cancellation handler
+    ///
+    /// # Errors
+    ///
+    /// If the steps are invalid, the errors of [`CliPrompt::prompt_wizard`] will be returned.
+    /// If a step chooses an unknown step as the next one, [`InvalidFormError`](CliPromptError::InvalidFormError) will be returned.
+    ///
+    /// If users cancel the group, [`Cancelled`](CliPromptError::Cancelled) will be returned after the handler is called.
+    ///
+    /// If the answers can't be converted into `T`, [`InvalidAnswersError`](InvalidAnswersError) will be returned.
+    /// ```
+    /// use cli_prompts_rs::{CliPrompt, PromptGroup, WizardAnswers};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    ///
+    /// let result = cli_prompt.prompt_group::<WizardAnswers>(PromptGroup::new(vec![]));
+    ///
+    /// assert!(result.is_err());
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.to_string(), "steps is empty");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, FormField, PromptGroup, PromptSelectOption, WizardAnswers, WizardStep};
+    ///
+    /// struct Install {
+    ///     kind: String,
+    ///     path: Option<String>,
+    ///     telemetry: bool,
+    /// }
+    ///
+    /// impl TryFrom<WizardAnswers> for Install {
+    ///     type Error = String;
+    ///
+    ///     fn try_from(answers: WizardAnswers) -> Result<Self, Self::Error> {
+    ///         Ok(Install {
+    ///             kind: answers.text("kind").ok_or("kind is missing")?.to_string(),
+    ///             path: answers.text("path").map(String::from),
+    ///             telemetry: answers.bool("telemetry").unwrap_or(false),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let group = PromptGroup::new(vec![
+    ///     WizardStep::select(
+    ///         "kind",
+    ///         "Installation",
+    ///         vec![
+    ///             PromptSelectOption::new("default", "Default"),
+    ///             PromptSelectOption::new("custom", "Custom"),
+    ///         ],
+    ///     )
+    ///     // the default installation asks nothing more
+    ///     .with_next(|answers| (answers.text("kind") == Some("default")).then(|| "telemetry".to_string())),
+    ///     WizardStep::field(FormField::text("path", "Install path").with_default("/opt/app")),
+    ///     WizardStep::field(FormField::toggle("telemetry", "Send usage data?", "Yes", "No")),
+    /// ])
+    /// .with_on_cancel(|_| eprintln!("Installation cancelled"));
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let install: Install = cli_prompt.prompt_group(group).unwrap();
+    /// println!("{} {:?} {}", install.kind, install.path, install.telemetry);
+    /// ```
+    pub fn prompt_group<T>(&mut self, group: PromptGroup) -> Result<T, CliPromptError>
+    where
+        T: TryFrom<WizardAnswers>,
+        T::Error: Display,
+    {
+        match self.run_wizard(&group.steps)? {
+            WizardEnd::Finished(answers) => {
+                T::try_from(answers).map_err(|error| InvalidAnswersError {
+                    message: error.to_string(),
+                })
+            }
+            WizardEnd::Cancelled(answers) => {
+                if let Some(on_cancel) = group.on_cancel {
+                    on_cancel(&answers);
+                }
+
+                Err(CliPromptError::Cancelled)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::build_prefix_map;
+    use crate::{FormField, PromptSelectOption};
+    use console::style;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn build_group() -> PromptGroup {
+        PromptGroup::new(vec![
+            WizardStep::select(
+                "kind",
+                "Installation",
+                vec![
+                    PromptSelectOption::new("default", "Default"),
+                    PromptSelectOption::new("custom", "Custom"),
+                ],
+            )
+            .with_next(|answers| {
+                (answers.text("kind") == Some("default")).then(|| "telemetry".to_string())
+            }),
+            WizardStep::field(FormField::text("path", "Path").with_default("/opt")),
+            WizardStep::field(FormField::toggle("docker", "Docker", "Yes", "No")),
+            WizardStep::field(FormField::text("image", "Image").with_default("debian"))
+                .with_skip_if(|answers| answers.bool("docker") == Some(false)),
+            WizardStep::field(FormField::toggle("telemetry", "Telemetry", "Yes", "No")),
+        ])
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Install {
+        path: String,
+        telemetry: bool,
+    }
+
+    impl TryFrom<WizardAnswers> for Install {
+        type Error = String;
+
+        fn try_from(answers: WizardAnswers) -> Result<Self, Self::Error> {
+            Ok(Install {
+                path: answers.text("path").ok_or("path is missing")?.to_string(),
+                telemetry: answers.bool("telemetry").ok_or("telemetry is missing")?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_prompt_group_next() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter", "enter"]);
+
+        let answers: WizardAnswers = cli_prompt.prompt_group(build_group()).unwrap();
+
+        assert_eq!(answers.text("kind"), Some("default"));
+        assert_eq!(answers.get("path"), None);
+        assert_eq!(answers.bool("telemetry"), Some(true));
+
+        let step = style(prefix_map.get("s_step_submit").unwrap()).magenta();
+        let bar = prefix_map.get("s_bar").unwrap();
+        assert_eq!(
+            format!(
+                "{step} Installation\n{bar} Default\n{bar}\n{step} Telemetry\n{bar} Yes\n{bar}\n",
+                step = step,
+                bar = bar
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_group_skip() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&[
+            "arrow down",
+            "enter",
+            "enter",
+            "arrow right",
+            "enter",
+            "arrow right",
+            "enter",
+        ]);
+
+        let answers: WizardAnswers = cli_prompt.prompt_group(build_group()).unwrap();
+
+        assert_eq!(answers.text("path"), Some("/opt"));
+        assert_eq!(answers.bool("docker"), Some(false));
+        assert_eq!(answers.get("image"), None);
+        assert_eq!(answers.bool("telemetry"), Some(false));
+    }
+
+    #[test]
+    fn test_prompt_group_back_forgets_skipped_answers() {
+        let mut cli_prompt = CliPrompt::new();
+        // answering "image", then going back to turn Docker off skips it
+        cli_prompt.push_key_inputs(&[
+            "arrow down",
+            "enter",
+            "enter",
+            "enter",
+            "enter",
+            "escape",
+            "escape",
+            "arrow right",
+            "enter",
+            "enter",
+        ]);
+
+        let answers: WizardAnswers = cli_prompt.prompt_group(build_group()).unwrap();
+
+        assert_eq!(answers.bool("docker"), Some(false));
+        assert_eq!(answers.get("image"), None);
+        assert_eq!(answers.bool("telemetry"), Some(true));
+    }
+
+    #[test]
+    fn test_prompt_group_typed() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["arrow down", "enter", "enter", "enter", "enter", "enter"]);
+
+        let install: Install = cli_prompt.prompt_group(build_group()).unwrap();
+
+        assert_eq!(
+            install,
+            Install {
+                path: "/opt".to_string(),
+                telemetry: true,
+            }
+        );
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter", "enter"]);
+
+        let result = cli_prompt.prompt_group::<Install>(build_group());
+
+        assert_eq!(result.unwrap_err().to_string(), "path is missing");
+    }
+
+    fn cancel_group(keys: &[&str]) -> Option<WizardAnswers> {
+        let cancelled = Rc::new(RefCell::new(None));
+        let group = build_group().with_on_cancel({
+            let cancelled = Rc::clone(&cancelled);
+            move |answers| *cancelled.borrow_mut() = Some(answers.clone())
+        });
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(keys);
+
+        let result = cli_prompt.prompt_group::<WizardAnswers>(group);
+
+        assert!(matches!(result, Err(CliPromptError::Cancelled)));
+        cancelled.take()
+    }
+
+    #[test]
+    fn test_prompt_group_cancel() {
+        // going back to the first step keeps the answers users gave
+        let answers =
+            cancel_group(&["arrow down", "enter", "enter", "escape", "escape", "escape"]).unwrap();
+
+        assert_eq!(answers.text("kind"), Some("custom"));
+        assert_eq!(answers.text("path"), Some("/opt"));
+        assert_eq!(answers.get("docker"), None);
+
+        let answers = cancel_group(&["arrow down", "enter", "enter", "ctrl c"]).unwrap();
+
+        assert_eq!(answers.text("kind"), Some("custom"));
+        assert_eq!(answers.text("path"), Some("/opt"));
+
+        let answers = cancel_group(&["ctrl c"]).unwrap();
+
+        assert_eq!(answers, WizardAnswers::default());
+    }
+
+    #[test]
+    fn test_prompt_group_unknown_next() {
+        let group = PromptGroup::new(vec![
+            WizardStep::field(FormField::text("a", "A")).with_next(|_| Some("b".to_string()))
+        ]);
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter"]);
+
+        let result = cli_prompt.prompt_group::<WizardAnswers>(group);
+
+        assert!(matches!(
+            result,
+            Err(CliPromptError::InvalidFormError { .. })
+        ));
+    }
+}
@@ -0,0 +1,463 @@
+use crate::cli_prompt_error::CliPromptError::{
+    self, InvalidMaxChoiceNumError, OptionsVecEmptyError,
+};
+use crate::{CliPrompt, Key, MessageType, PromptSelectOption};
+
+/// A named group of options for [`prompt_multi_select_grouped`](CliPrompt::prompt_multi_select_grouped).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PromptSelectGroup {
+    pub name: String,
+    pub options: Vec<PromptSelectOption>,
+}
+
+impl PromptSelectGroup {
+    pub fn new(name: &str, options: Vec<PromptSelectOption>) -> Self {
+        Self {
+            name: name.to_string(),
+            options,
+        }
+    }
+}
+
+/// A row of the grouped multi select prompt, addressing a group header or an option by indexes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum GroupedRow {
+    Header(usize),
+    Option(usize, usize),
+}
+
+impl CliPrompt {
+    /// Prints the prompt message and let users choose multiple options among the provided groups.
+    /// Group headers can't be chosen themselves, but choosing a header toggles all of its options.
+    /// The keys are same as [`CliPrompt::prompt_multi_select`].
+    ///
+    /// Returns the selected options as `Vector` of pairs of the group name and [`PromptSelectOption`] wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_multi_select_grouped_with_max_choice_num`]
+    /// with the number of all options.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `groups` - the list of groups for selection
+    ///
+    /// # Errors
+    ///
+    /// If `groups` has no options, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// ```
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectGroup};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let groups = vec![PromptSelectGroup::new("Frontend", vec![])];
+    ///
+    /// let result = cli_prompt.prompt_multi_select_grouped("Pick frameworks", groups);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectGroup, PromptSelectOption};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let groups = vec![
+    ///     PromptSelectGroup::new(
+    ///         "Frontend",
+    ///         vec![
+    ///             PromptSelectOption::new("react", "React"),
+    ///             PromptSelectOption::new("vue", "Vue"),
+    ///         ],
+    ///     ),
+    ///     PromptSelectGroup::new(
+    ///         "Backend",
+    ///         vec![
+    ///             PromptSelectOption::new("axum", "Axum"),
+    ///             PromptSelectOption::new("rails", "Rails"),
+    ///         ],
+    ///     ),
+    /// ];
+    /// let selected_options = cli_prompt.prompt_multi_select_grouped("Pick frameworks", groups).unwrap();
+    /// for (group, option) in selected_options {
+    ///     println!("{} from {}", option, group);
+    /// }
+    /// ```
+    pub fn prompt_multi_select_grouped(
+        &mut self,
+        message: &str,
+        groups: Vec<PromptSelectGroup>,
+    ) -> Result<Vec<(String, PromptSelectOption)>, CliPromptError> {
+        let options_len = groups.iter().map(|group| group.options.len()).sum();
+        self.prompt_multi_select_grouped_with_max_choice_num(message, groups, options_len)
+    }
+
+    /// Prints the prompt message and let users choose up to `max_choice_num` options among the provided groups.
+    /// Choosing a header checks all of its options only if they fit in `max_choice_num`,
+    /// and unchecks them if all of them are already checked.
+    /// The other behaviors are same as [`CliPrompt::prompt_multi_select_grouped`].
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `groups` - the list of groups for selection
+    /// * `max_choice_num` - the maximum number of choice across all groups. Must be greater than 0 and equal or less than the number of all options.
+    ///
+    /// # Errors
+    ///
+    /// If `groups` has no options, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// If `max_choice_num` is zero or greater than the number of all options, [`InvalidMaxChoiceNumError`](InvalidMaxChoiceNumError) will be returned.
+    /// ```
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectGroup, PromptSelectOption};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let groups = vec![PromptSelectGroup::new(
+    ///     "Frontend",
+    ///     vec![PromptSelectOption::new("react", "React")],
+    /// )];
+    ///
+    /// let result = cli_prompt.prompt_multi_select_grouped_with_max_choice_num("message", groups, 2);
+    ///
+    /// assert!(result.is_err());
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.to_string(), "max_choice_num must be less or equal than options length");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectGroup, PromptSelectOption};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let groups = vec![
+    ///     PromptSelectGroup::new("Frontend", vec![PromptSelectOption::new("react", "React")]),
+    ///     PromptSelectGroup::new("Backend", vec![PromptSelectOption::new("axum", "Axum")]),
+    /// ];
+    /// let selected_options = cli_prompt
+    ///     .prompt_multi_select_grouped_with_max_choice_num("Pick a framework", groups, 1)
+    ///     .unwrap();
+    /// println!("{:?}", selected_options);
+    /// ```
+    pub fn prompt_multi_select_grouped_with_max_choice_num(
+        &mut self,
+        message: &str,
+        groups: Vec<PromptSelectGroup>,
+        max_choice_num: usize,
+    ) -> Result<Vec<(String, PromptSelectOption)>, CliPromptError> {
+        let options_len: usize = groups.iter().map(|group| group.options.len()).sum();
+
+        if options_len == 0 {
+            return Err(OptionsVecEmptyError {
+                message: "options is empty".to_string(),
+            });
+        }
+
+        if max_choice_num > options_len {
+            return Err(InvalidMaxChoiceNumError {
+                message: "max_choice_num must be less or equal than options length".to_string(),
+            });
+        }
+
+        if max_choice_num == 0 {
+            return Err(InvalidMaxChoiceNumError {
+                message: "max_choice_num must be greater than 0".to_string(),
+            });
+        }
+
+        let rows = grouped_rows(&groups);
+        let rows_num = rows.len();
+        let mut is_selected: Vec<Vec<bool>> = groups
+            .iter()
+            .map(|group| vec![false; group.options.len()])
+            .collect();
+        let mut choice = 0;
+
+        self.term.hide_cursor()?;
+        let frame = self.grouped_frame(message, &groups, &rows, &is_selected, choice, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match self.term.read_key()? {
+                Key::ArrowUp => choice = (choice + rows_num) % (rows_num + 1),
+                Key::ArrowDown => choice = (choice + 1) % (rows_num + 1),
+                Key::Enter if choice == rows_num => break,
+                Key::Enter => {
+                    let selected_num: usize = is_selected
+                        .iter()
+                        .map(|group| group.iter().filter(|&&is_selected| is_selected).count())
+                        .sum();
+
+                    match rows[choice] {
+                        GroupedRow::Header(g) => {
+                            let unselected_num = is_selected[g].iter().filter(|&&s| !s).count();
+                            if unselected_num == 0 {
+                                is_selected[g].fill(false);
+                            } else if selected_num + unselected_num <= max_choice_num {
+                                is_selected[g].fill(true);
+                            } else {
+                                continue;
+                            }
+                        }
+                        GroupedRow::Option(g, o) => {
+                            if is_selected[g][o] {
+                                is_selected[g][o] = false;
+                            } else if selected_num < max_choice_num {
+                                is_selected[g][o] = true;
+                            } else {
+                                continue;
+                            }
+                        }
+                    }
+                }
+                _ => continue,
+            }
+
+            let frame = self.grouped_frame(message, &groups, &rows, &is_selected, choice, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.grouped_frame(message, &groups, &rows, &is_selected, choice, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(selected_grouped_options(&groups, &is_selected))
+    }
+
+    /// Builds the frame of the grouped multi select prompt, with the confirm row at the bottom.
+    /// Once submitted, only the labels of the selected options are drawn.
+    fn grouped_frame(
+        &self,
+        message: &str,
+        groups: &[PromptSelectGroup],
+        rows: &[GroupedRow],
+        is_selected: &[Vec<bool>],
+        choice: usize,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            let labels: Vec<String> = selected_grouped_options(groups, is_selected)
+                .into_iter()
+                .map(|(_, option)| option.label)
+                .collect();
+            return vec![question, self.format_bar_line(&labels.join(", "))];
+        }
+
+        let checkbox = |is_checked: bool| {
+            if is_checked {
+                self.apply_style(&self.colors.selected, &self.s_checkbox_active)
+                    .to_string()
+            } else {
+                self.s_checkbox_inactive.clone()
+            }
+        };
+
+        let mut lines = vec![question];
+        for (i, row) in rows.iter().enumerate() {
+            let label = match *row {
+                GroupedRow::Header(g) => format!(
+                    "{} {}",
+                    checkbox(is_selected[g].iter().all(|&s| s)),
+                    self.apply_style(&self.colors.hint, &groups[g].name)
+                ),
+                GroupedRow::Option(g, o) => format!(
+                    "  {} {}",
+                    checkbox(is_selected[g][o]),
+                    groups[g].options[o].label
+                ),
+            };
+            lines.push(self.format_radio_option(&label, i == choice));
+        }
+        lines.push(self.format_radio_option("confirm", choice == rows.len()));
+
+        lines
+    }
+}
+
+/// Lists every group header followed by its options. Groups without options are skipped.
+fn grouped_rows(groups: &[PromptSelectGroup]) -> Vec<GroupedRow> {
+    let mut rows = Vec::new();
+    for (g, group) in groups.iter().enumerate() {
+        if group.options.is_empty() {
+            continue;
+        }
+
+        rows.push(GroupedRow::Header(g));
+        rows.extend((0..group.options.len()).map(|o| GroupedRow::Option(g, o)));
+    }
+
+    rows
+}
+
+fn selected_grouped_options(
+    groups: &[PromptSelectGroup],
+    is_selected: &[Vec<bool>],
+) -> Vec<(String, PromptSelectOption)> {
+    let mut selected_options = Vec::new();
+    for (group, is_selected) in groups.iter().zip(is_selected) {
+        for (option, _) in group
+            .options
+            .iter()
+            .zip(is_selected)
+            .filter(|(_, &is_selected)| is_selected)
+        {
+            selected_options.push((group.name.clone(), option.clone()));
+        }
+    }
+
+    selected_options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_groups() -> Vec<PromptSelectGroup> {
+        vec![
+            PromptSelectGroup::new(
+                "Frontend",
+                vec![
+                    PromptSelectOption::new("react", "React"),
+                    PromptSelectOption::new("vue", "Vue"),
+                ],
+            ),
+            PromptSelectGroup::new("Empty", vec![]),
+            PromptSelectGroup::new("Backend", vec![PromptSelectOption::new("axum", "Axum")]),
+        ]
+    }
+
+    fn values(options: &[(String, PromptSelectOption)]) -> Vec<(&str, &str)> {
+        options
+            .iter()
+            .map(|(group, option)| (option.value.as_str(), group.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_grouped_rows() {
+        assert_eq!(
+            grouped_rows(&build_groups()),
+            vec![
+                GroupedRow::Header(0),
+                GroupedRow::Option(0, 0),
+                GroupedRow::Option(0, 1),
+                GroupedRow::Header(2),
+                GroupedRow::Option(2, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grouped_frame() {
+        let prefix_map = build_prefix_map();
+        let groups = build_groups();
+        let rows = grouped_rows(&groups);
+        let is_selected = vec![vec![true, true], vec![], vec![false]];
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+
+        let frame = cli_prompt.grouped_frame("message", &groups, &rows, &is_selected, 1, true);
+
+        assert_eq!(
+            frame[1],
+            format!(
+                "{} {} {} Frontend",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_radio_inactive").unwrap(),
+                prefix_map.get("s_checkbox_active").unwrap()
+            )
+        );
+        assert_eq!(
+            frame[2],
+            format!(
+                "{} {}   {} React",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_radio_active").unwrap(),
+                prefix_map.get("s_checkbox_active").unwrap()
+            )
+        );
+        assert_eq!(
+            frame[6],
+            format!(
+                "{} {} confirm",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_radio_inactive").unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_multi_select_grouped() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        // toggle the "Frontend" header, uncheck "Vue", check "Axum" and confirm
+        cli_prompt.push_key_inputs(&[
+            "enter",
+            "arrow down",
+            "arrow down",
+            "enter",
+            "arrow down",
+            "arrow down",
+            "enter",
+            "arrow down",
+            "enter",
+        ]);
+
+        let result = cli_prompt
+            .prompt_multi_select_grouped("message", build_groups())
+            .unwrap();
+
+        assert_eq!(
+            values(&result),
+            vec![("react", "Frontend"), ("axum", "Backend")]
+        );
+        assert_eq!(
+            format!(
+                "{} message\n{} React, Axum\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_multi_select_grouped_with_max_choice_num() {
+        let mut cli_prompt = CliPrompt::new();
+        // the "Frontend" header and "React" don't fit in 1 choice once "Axum" is checked
+        cli_prompt.push_key_inputs(&[
+            "enter", "arrow up", "arrow up", "enter", "arrow up", "arrow up", "arrow up", "enter",
+            "arrow up", "arrow up", "enter",
+        ]);
+
+        let result = cli_prompt
+            .prompt_multi_select_grouped_with_max_choice_num("message", build_groups(), 1)
+            .unwrap();
+
+        assert_eq!(values(&result), vec![("axum", "Backend")]);
+    }
+
+    #[test]
+    fn test_prompt_multi_select_grouped_invalid() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_multi_select_grouped("message", vec![]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+        assert!(matches!(
+            cli_prompt.prompt_multi_select_grouped_with_max_choice_num(
+                "message",
+                build_groups(),
+                0
+            ),
+            Err(InvalidMaxChoiceNumError { .. })
+        ));
+    }
+}
@@ -0,0 +1,341 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidMaxLinesError};
+use crate::line_buffer::LineBuffer;
+use crate::prompts::{edit_line, CTRL_D};
+use crate::{CliPrompt, Key, MessageType};
+
+impl CliPrompt {
+    /// Prints the prompt message and let users type text over multiple lines.
+    /// Enter key inserts a new line, arrow keys move the cursor between lines,
+    /// and Ctrl+D or Alt+Enter submits the text. Each line supports the same editing keys as [`CliPrompt::prompt_text`].
+    /// When stdin is not a terminal, such as piped input, the lines are read until EOF.
+    ///
+    /// Returns the lines joined by `\n` as `String` wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_multiline_with_max_lines`] without limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let description = cli_prompt.prompt_multiline("Describe the change").unwrap();
+    /// println!("{}", description);
+    /// ```
+    pub fn prompt_multiline(&mut self, message: &str) -> Result<String, CliPromptError> {
+        self.prompt_multiline_inner(message, None)
+    }
+
+    /// Prints the prompt message and let users type text up to `max_lines` lines.
+    /// Enter key does nothing once the text has `max_lines` lines.
+    ///
+    /// Returns the lines joined by `\n` as `String` wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `max_lines` - the maximum number of lines. Must be greater than 0.
+    ///
+    /// # Errors
+    ///
+    /// If `max_lines` is zero, [`InvalidMaxLinesError`](InvalidMaxLinesError) will be returned.
+    ///
+    /// ```
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let result = cli_prompt.prompt_multiline_with_max_lines("message", 0);
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().to_string(), "max_lines must be greater than 0");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let commit_message = cli_prompt
+    ///     .prompt_multiline_with_max_lines("Commit message", 5)
+    ///     .unwrap();
+    /// println!("{}", commit_message);
+    /// ```
+    pub fn prompt_multiline_with_max_lines(
+        &mut self,
+        message: &str,
+        max_lines: usize,
+    ) -> Result<String, CliPromptError> {
+        if max_lines == 0 {
+            return Err(InvalidMaxLinesError {
+                message: "max_lines must be greater than 0".to_string(),
+            });
+        }
+
+        self.prompt_multiline_inner(message, Some(max_lines))
+    }
+
+    fn prompt_multiline_inner(
+        &mut self,
+        message: &str,
+        max_lines: Option<usize>,
+    ) -> Result<String, CliPromptError> {
+        // keys can't be read without a terminal, so piped input is read until EOF
+        if !self.term.is_term() {
+            self.term
+                .write_line(&self.format_prefix(message.to_string(), MessageType::Question))?;
+            let mut lines = self.read_lines_to_end()?;
+            if let Some(max_lines) = max_lines {
+                lines.truncate(max_lines);
+            }
+            for line in &lines {
+                self.term.write_line(&self.format_bar_line(line))?;
+            }
+            self.print_empty_line()?;
+
+            return Ok(lines.join("\n"));
+        }
+
+        let mut lines = vec![LineBuffer::new("")];
+        let mut row = 0;
+
+        self.term.hide_cursor()?;
+        let frame = self.multiline_frame(message, &lines, Some(row), max_lines);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match self.term.read_key()? {
+                Key::Char(CTRL_D) => break,
+                Key::UnknownEscSeq(sequence) if sequence == ['\r'] || sequence == ['\n'] => break,
+                Key::Enter => {
+                    // `Option::is_none_or` needs Rust 1.82
+                    #[allow(clippy::unnecessary_map_or)]
+                    let can_insert = max_lines.map_or(true, |max_lines| lines.len() < max_lines);
+                    if can_insert {
+                        let rest = lines[row].split_off();
+                        row += 1;
+                        lines.insert(row, rest);
+                    }
+                }
+                Key::Backspace => {
+                    if !lines[row].delete_backward() && row > 0 {
+                        let current = lines.remove(row);
+                        row -= 1;
+                        lines[row].move_end();
+                        lines[row].append(&current);
+                    }
+                }
+                Key::Del => {
+                    if !lines[row].delete_forward() && row + 1 < lines.len() {
+                        let next = lines.remove(row + 1);
+                        lines[row].append(&next);
+                    }
+                }
+                Key::ArrowLeft => {
+                    if !lines[row].move_left() && row > 0 {
+                        row -= 1;
+                        lines[row].move_end();
+                    }
+                }
+                Key::ArrowRight => {
+                    if !lines[row].move_right() && row + 1 < lines.len() {
+                        row += 1;
+                        lines[row].move_home();
+                    }
+                }
+                Key::ArrowUp => {
+                    if row > 0 {
+                        let cursor = lines[row].cursor();
+                        row -= 1;
+                        lines[row].set_cursor(cursor);
+                    }
+                }
+                Key::ArrowDown => {
+                    if row + 1 < lines.len() {
+                        let cursor = lines[row].cursor();
+                        row += 1;
+                        lines[row].set_cursor(cursor);
+                    }
+                }
+                key => {
+                    if !edit_line(&mut lines[row], &key) {
+                        continue;
+                    }
+                }
+            }
+
+            let frame = self.multiline_frame(message, &lines, Some(row), max_lines);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.multiline_frame(message, &lines, None, max_lines);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(lines
+            .iter()
+            .map(|line| line.text())
+            .collect::<Vec<String>>()
+            .join("\n"))
+    }
+
+    /// Builds the frame of the multiline prompt. The cursor and the hint are drawn only while `cursor_row` is given.
+    fn multiline_frame(
+        &self,
+        message: &str,
+        lines: &[LineBuffer],
+        cursor_row: Option<usize>,
+        max_lines: Option<usize>,
+    ) -> Vec<String> {
+        let mut frame = vec![self.format_prefix(message.to_string(), MessageType::Question)];
+
+        for (i, line) in lines.iter().enumerate() {
+            frame.push(if cursor_row == Some(i) {
+                self.format_bar_line(&self.format_cursor_line(line))
+            } else {
+                self.format_bar_line(&line.text())
+            });
+        }
+
+        if cursor_row.is_some() {
+            frame.push(self.format_hint(&match max_lines {
+                Some(max_lines) => format!(
+                    "ctrl+d or alt+enter to submit ({}/{} lines)",
+                    lines.len(),
+                    max_lines
+                ),
+                None => "ctrl+d or alt+enter to submit".to_string(),
+            }));
+        }
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::build_prefix_map;
+    use crate::CliPrompt;
+    use console::style;
+
+    #[test]
+    fn test_prompt_multiline_message() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["h", "i", "enter", "y", "o", "\x04"]);
+
+        let result = cli_prompt.prompt_multiline("message").unwrap();
+
+        assert_eq!(result, "hi\nyo");
+        assert_eq!(
+            format!(
+                "{} message\n{} hi\n{} yo\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_multiline_without_terminal() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_term_input("first\nsecond\nthird\n");
+
+        let result = cli_prompt.prompt_multiline("message").unwrap();
+
+        assert_eq!(result, "first\nsecond\nthird");
+        assert_eq!(
+            format!(
+                "{step} message\n{bar} first\n{bar} second\n{bar} third\n{bar}\n",
+                step = style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                bar = prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_term_input("first\nsecond\nthird\n");
+
+        let result = cli_prompt
+            .prompt_multiline_with_max_lines("message", 2)
+            .unwrap();
+
+        assert_eq!(result, "first\nsecond");
+    }
+
+    #[test]
+    fn test_prompt_multiline_alt_enter() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["a", "alt enter"]);
+
+        let result = cli_prompt.prompt_multiline("message").unwrap();
+
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn test_prompt_multiline_move_between_lines() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&[
+            "a",
+            "b",
+            "c",
+            "enter",
+            "d",
+            "arrow up",
+            "x",
+            "arrow down",
+            "end",
+            "y",
+            "\x04",
+        ]);
+
+        let result = cli_prompt.prompt_multiline("message").unwrap();
+
+        assert_eq!(result, "axbc\ndy");
+    }
+
+    #[test]
+    fn test_prompt_multiline_backspace_joins_lines() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["a", "enter", "b", "arrow left", "backspace", "\x04"]);
+
+        let result = cli_prompt.prompt_multiline("message").unwrap();
+
+        assert_eq!(result, "ab");
+    }
+
+    #[test]
+    fn test_prompt_multiline_with_max_lines() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["a", "enter", "b", "enter", "c", "\x04"]);
+
+        let result = cli_prompt
+            .prompt_multiline_with_max_lines("message", 2)
+            .unwrap();
+
+        assert_eq!(result, "a\nbc");
+    }
+
+    #[test]
+    fn test_prompt_multiline_with_max_lines_zero() {
+        let mut cli_prompt = CliPrompt::new();
+
+        let result = cli_prompt.prompt_multiline_with_max_lines("message", 0);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "max_lines must be greater than 0"
+        );
+    }
+}
@@ -0,0 +1,479 @@
+use crate::cli_prompt_error::CliPromptError;
+use crate::{CliPrompt, Key, MessageType};
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf, MAIN_SEPARATOR_STR};
+
+/// Maximum number of entries shown at once by [`prompt_path`](CliPrompt::prompt_path).
+const MAX_VISIBLE_ENTRIES: usize = 10;
+
+/// Which entries [`prompt_path_with_options`](CliPrompt::prompt_path_with_options) lists.
+///
+/// # Example
+/// ```no_run
+/// use cli_prompts_rs::{CliPrompt, PathPickerOptions};
+///
+/// let options = PathPickerOptions {
+///     extensions: vec!["toml".to_string(), "json".to_string()],
+///     ..PathPickerOptions::default()
+/// };
+///
+/// let mut cli_prompt = CliPrompt::new();
+/// let config = cli_prompt
+///     .prompt_path_with_options("Config file", ".", options)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct PathPickerOptions {
+    /// Extensions of the files to list, like `toml`. Every file is listed when empty.
+    pub extensions: Vec<String>,
+    /// Lists only directories, and picks the directory being browsed instead of a file.
+    pub only_dirs: bool,
+    /// Lists the entries whose names start with `.`.
+    pub show_hidden: bool,
+}
+
+impl PathPickerOptions {
+    fn accepts(&self, name: &str, is_dir: bool) -> bool {
+        if name.starts_with('.') && !self.show_hidden {
+            return false;
+        }
+        if is_dir {
+            return true;
+        }
+        if self.only_dirs {
+            return false;
+        }
+
+        self.extensions.is_empty()
+            || Path::new(name)
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    self.extensions.iter().any(|accepted| {
+                        accepted
+                            .trim_start_matches('.')
+                            .eq_ignore_ascii_case(extension)
+                    })
+                })
+    }
+}
+
+struct Entry {
+    /// The name joined to the directory, which may not be valid UTF-8
+    file_name: OsString,
+    /// The name shown and filtered, with invalid UTF-8 replaced
+    name: String,
+    is_dir: bool,
+}
+
+/// Lists the entries of `dir` accepted by the options, directories first.
+fn read_entries(dir: &Path, options: &PathPickerOptions) -> io::Result<Vec<Entry>> {
+    let mut entries: Vec<Entry> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy().into_owned();
+            let is_dir = entry.path().is_dir();
+            options.accepts(&name, is_dir).then_some(Entry {
+                file_name,
+                name,
+                is_dir,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(entries)
+}
+
+impl CliPrompt {
+    /// Prints the prompt message and let users browse the filesystem from `start_dir` to pick a file.
+    /// Users can change the selection by Arrow Up and Arrow Down key, and type to filter the entries.
+    /// Enter key descends into the selected directory or picks the selected file,
+    /// and Backspace key goes up to the parent directory when the filter is empty.
+    ///
+    /// Returns the absolute path of the picked file as `PathBuf` wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_path_with_options`] with default options.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `start_dir` - the directory to start browsing from
+    ///
+    /// # Errors
+    ///
+    /// If `start_dir` can't be read, [`IoError`](CliPromptError::IoError) will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let path = cli_prompt.prompt_path("Pick a file", ".").unwrap();
+    /// println!("{}", path.display());
+    /// ```
+    pub fn prompt_path<P: AsRef<Path>>(
+        &mut self,
+        message: &str,
+        start_dir: P,
+    ) -> Result<PathBuf, CliPromptError> {
+        self.prompt_path_with_options(message, start_dir, PathPickerOptions::default())
+    }
+
+    /// Prints the prompt message and let users browse the filesystem from `start_dir`,
+    /// listing the entries accepted by `options`.
+    ///
+    /// With [`only_dirs`](PathPickerOptions::only_dirs), the first entry `.` picks the directory being browsed.
+    /// The other keys are same as [`CliPrompt::prompt_path`].
+    ///
+    /// Returns the absolute path of the picked entry as `PathBuf` wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `start_dir` - the directory to start browsing from
+    /// * `options` - which entries to list
+    ///
+    /// # Errors
+    ///
+    /// If `start_dir` can't be read, [`IoError`](CliPromptError::IoError) will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, PathPickerOptions};
+    ///
+    /// let options = PathPickerOptions {
+    ///     only_dirs: true,
+    ///     ..PathPickerOptions::default()
+    /// };
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let output_dir = cli_prompt
+    ///     .prompt_path_with_options("Output directory", ".", options)
+    ///     .unwrap();
+    /// ```
+    pub fn prompt_path_with_options<P: AsRef<Path>>(
+        &mut self,
+        message: &str,
+        start_dir: P,
+        options: PathPickerOptions,
+    ) -> Result<PathBuf, CliPromptError> {
+        let mut dir = fs::canonicalize(start_dir)?;
+        let mut entries = read_entries(&dir, &options)?;
+        let mut filter = String::new();
+        let mut choice = 0;
+
+        self.term.hide_cursor()?;
+        let frame = self.path_frame(
+            message,
+            &dir,
+            &visible(&entries, &filter, &options),
+            &filter,
+            choice,
+        );
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        let picked = loop {
+            let matches = visible(&entries, &filter, &options);
+            match self.term.read_key()? {
+                Key::ArrowUp if !matches.is_empty() => {
+                    choice = (choice + matches.len() - 1) % matches.len();
+                }
+                Key::ArrowDown if !matches.is_empty() => {
+                    choice = (choice + 1) % matches.len();
+                }
+                Key::Enter => match matches.get(choice) {
+                    None => continue,
+                    Some(PathChoice::CurrentDir) => break dir,
+                    Some(PathChoice::Entry(entry)) if entry.is_dir => {
+                        let child = dir.join(&entry.file_name);
+                        let Ok(child_entries) = read_entries(&child, &options) else {
+                            continue;
+                        };
+                        dir = child;
+                        entries = child_entries;
+                        filter.clear();
+                        choice = 0;
+                    }
+                    Some(PathChoice::Entry(entry)) => break dir.join(&entry.file_name),
+                },
+                Key::Backspace if !filter.is_empty() => {
+                    filter.pop();
+                    choice = 0;
+                }
+                Key::Backspace => {
+                    let Some(parent) = dir.parent().map(Path::to_path_buf) else {
+                        continue;
+                    };
+                    let Ok(parent_entries) = read_entries(&parent, &options) else {
+                        continue;
+                    };
+                    let previous = dir.file_name().map(OsStr::to_os_string);
+                    dir = parent;
+                    entries = parent_entries;
+                    choice = visible(&entries, &filter, &options)
+                        .iter()
+                        .position(|path_choice| {
+                            matches!(path_choice, PathChoice::Entry(entry) if Some(&entry.file_name) == previous.as_ref())
+                        })
+                        .unwrap_or(0);
+                }
+                Key::Char(c) if !c.is_control() => {
+                    filter.push(c);
+                    choice = 0;
+                }
+                _ => continue,
+            }
+
+            let frame = self.path_frame(
+                message,
+                &dir,
+                &visible(&entries, &filter, &options),
+                &filter,
+                choice,
+            );
+            height = self.draw_frame(&frame, height)?;
+        };
+
+        let frame = vec![
+            self.format_prefix(message.to_string(), MessageType::Question),
+            self.format_bar_line(&picked.display().to_string()),
+        ];
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(picked)
+    }
+
+    fn path_frame(
+        &self,
+        message: &str,
+        dir: &Path,
+        matches: &[PathChoice],
+        filter: &str,
+        choice: usize,
+    ) -> Vec<String> {
+        let mut frame = vec![
+            self.format_prefix(message.to_string(), MessageType::Question),
+            self.format_bar_line(&format!(
+                "{}{}",
+                self.apply_style(&self.colors.hint, dir.join("").display()),
+                filter
+            )),
+        ];
+
+        if matches.is_empty() {
+            frame.push(self.format_hint("no matches"));
+        }
+
+        let start = (choice + 1).saturating_sub(MAX_VISIBLE_ENTRIES);
+        for (i, path_choice) in matches
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MAX_VISIBLE_ENTRIES)
+        {
+            let label = match path_choice {
+                PathChoice::CurrentDir => ".".to_string(),
+                PathChoice::Entry(entry) if entry.is_dir => {
+                    format!("{}{}", entry.name, MAIN_SEPARATOR_STR)
+                }
+                PathChoice::Entry(entry) => entry.name.clone(),
+            };
+            frame.push(self.format_radio_option(&label, i == choice));
+        }
+
+        if matches.len() > MAX_VISIBLE_ENTRIES {
+            frame.push(self.format_hint(&format!("({}/{})", choice + 1, matches.len())));
+        }
+
+        frame
+    }
+}
+
+enum PathChoice<'a> {
+    /// Picks the directory being browsed, listed only with [`PathPickerOptions::only_dirs`]
+    CurrentDir,
+    Entry(&'a Entry),
+}
+
+/// Returns the choices whose names contain `filter`, ignoring case.
+fn visible<'a>(
+    entries: &'a [Entry],
+    filter: &str,
+    options: &PathPickerOptions,
+) -> Vec<PathChoice<'a>> {
+    let filter = filter.to_lowercase();
+    let current_dir = (options.only_dirs && filter.is_empty()).then_some(PathChoice::CurrentDir);
+
+    current_dir
+        .into_iter()
+        .chain(
+            entries
+                .iter()
+                .filter(|entry| entry.name.to_lowercase().contains(&filter))
+                .map(PathChoice::Entry),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn create_tree(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("cli_prompts_rs-{}-{}", name, std::process::id()));
+        fs::create_dir_all(dir.join("config").join("nested")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::write(dir.join("config").join("app.toml"), "").unwrap();
+        fs::write(dir.join("config").join("app.json"), "").unwrap();
+        fs::write(dir.join("readme.md"), "").unwrap();
+        fs::write(dir.join(".env"), "").unwrap();
+
+        fs::canonicalize(dir).unwrap()
+    }
+
+    #[test]
+    fn test_options_accepts() {
+        let options = PathPickerOptions {
+            extensions: vec![".TOML".to_string()],
+            ..PathPickerOptions::default()
+        };
+
+        assert!(options.accepts("app.toml", false));
+        assert!(!options.accepts("app.json", false));
+        assert!(options.accepts("config", true));
+        assert!(!options.accepts(".cargo", true));
+
+        let options = PathPickerOptions {
+            only_dirs: true,
+            show_hidden: true,
+            ..PathPickerOptions::default()
+        };
+
+        assert!(options.accepts(".cargo", true));
+        assert!(!options.accepts("app.toml", false));
+    }
+
+    #[test]
+    fn test_prompt_path() {
+        let dir = create_tree("path-pick");
+        let mut cli_prompt = CliPrompt::new();
+
+        // config/ comes first, then app.json and app.toml inside it
+        cli_prompt.push_key_inputs(&["enter", "arrow down", "arrow down", "enter"]);
+
+        let result = cli_prompt.prompt_path("message", &dir).unwrap();
+
+        assert_eq!(result, dir.join("config").join("app.toml"));
+
+        cli_prompt.push_key_inputs(&["enter", "backspace", "r", "e", "enter"]);
+
+        let result = cli_prompt.prompt_path("message", &dir).unwrap();
+
+        assert_eq!(result, dir.join("readme.md"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_prompt_path_non_utf8_name() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = create_tree("path-non-utf8");
+        let file_name = OsStr::from_bytes(b"\xffbad.md");
+        fs::write(dir.join(file_name), "").unwrap();
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["b", "a", "d", "enter"]);
+
+        let result = cli_prompt.prompt_path("message", &dir).unwrap();
+
+        assert_eq!(result, dir.join(file_name));
+        assert!(cli_prompt
+            .term
+            .get_output_string()
+            .contains("\u{fffd}bad.md"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prompt_path_with_options() {
+        let dir = create_tree("path-options");
+        let mut cli_prompt = CliPrompt::new();
+        let options = PathPickerOptions {
+            only_dirs: true,
+            show_hidden: true,
+            ..PathPickerOptions::default()
+        };
+
+        // ".", ".git/", "config/"
+        cli_prompt.push_key_inputs(&["arrow up", "enter", "enter"]);
+
+        let result = cli_prompt
+            .prompt_path_with_options("message", &dir, options.clone())
+            .unwrap();
+
+        assert_eq!(result, dir.join("config"));
+
+        cli_prompt.push_key_inputs(&["g", "i", "backspace", "enter", "enter"]);
+
+        let result = cli_prompt
+            .prompt_path_with_options("message", &dir, options)
+            .unwrap();
+
+        assert_eq!(result, dir.join(".git"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_path_frame() {
+        let dir = create_tree("path-frame");
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(crate::color::ColorMode::Never);
+        let options = PathPickerOptions {
+            extensions: vec!["md".to_string()],
+            ..PathPickerOptions::default()
+        };
+        let entries = read_entries(&dir, &options).unwrap();
+
+        let frame = cli_prompt.path_frame("message", &dir, &visible(&entries, "", &options), "", 1);
+
+        assert_eq!(
+            frame[1..],
+            [
+                format!("{} {}", cli_prompt.s_bar, dir.join("").display()),
+                format!(
+                    "{} {} config{}",
+                    cli_prompt.s_bar, cli_prompt.s_radio_inactive, MAIN_SEPARATOR_STR
+                ),
+                format!(
+                    "{} {} readme.md",
+                    cli_prompt.s_bar, cli_prompt.s_radio_active
+                ),
+            ]
+        );
+
+        let frame = cli_prompt.path_frame(
+            "message",
+            &dir,
+            &visible(&entries, "xyz", &options),
+            "xyz",
+            0,
+        );
+
+        assert_eq!(frame[2], format!("{} no matches", cli_prompt.s_bar));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,317 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidSliderError};
+use crate::{CliPrompt, Key, MessageType};
+
+/// Number of cells of the slider track, including the knob.
+const SLIDER_WIDTH: usize = 30;
+
+/// How many steps Page Up, Page Down and Shift with arrow keys move at once.
+const BIG_STEP_FACTOR: i64 = 10;
+
+impl CliPrompt {
+    /// Prints the prompt message with a horizontal slider and let users pick a number between `min` and `max`.
+    /// Arrow Left and Arrow Right move the knob by 1, Page Up and Page Down or Shift with Arrow Left and Arrow Right move it by 10,
+    /// Home and End move it to `min` and `max`. Users choose the value by Enter key.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_slider_with_step`] with `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `min` - the smallest value, inclusive
+    /// * `max` - the largest value, inclusive
+    /// * `initial` - the value selected at first. It is moved into the bounds if it is out of them.
+    ///
+    /// # Errors
+    ///
+    /// If `min` is not less than `max`, [`InvalidSliderError`](InvalidSliderError) will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let cpu = cli_prompt
+    ///     .prompt_slider("CPU limit (%)", 0, 100, 50)
+    ///     .unwrap();
+    /// println!("{}", cpu);
+    /// ```
+    pub fn prompt_slider(
+        &mut self,
+        message: &str,
+        min: i64,
+        max: i64,
+        initial: i64,
+    ) -> Result<i64, CliPromptError> {
+        self.prompt_slider_with_step(message, min, max, initial, 1)
+    }
+
+    /// Prints the prompt message with a horizontal slider and let users pick a number between `min` and `max`.
+    /// Arrow Left and Arrow Right move the knob by `step`, Page Up and Page Down or Shift with them move it by ten times `step`.
+    /// The other keys are same as [`CliPrompt::prompt_slider`].
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `min` - the smallest value, inclusive
+    /// * `max` - the largest value, inclusive
+    /// * `initial` - the value selected at first. It is moved into the bounds if it is out of them.
+    /// * `step` - the amount of a single move
+    ///
+    /// # Errors
+    ///
+    /// If `min` is not less than `max` or `step` is not positive, [`InvalidSliderError`](InvalidSliderError) will be returned.
+    ///
+    /// ```
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let result = cli_prompt.prompt_slider_with_step("message", 0, 10, 5, 0);
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().to_string(), "step must be positive");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let memory = cli_prompt
+    ///     .prompt_slider_with_step("Memory (MiB)", 256, 8192, 1024, 256)
+    ///     .unwrap();
+    /// println!("{}", memory);
+    /// ```
+    pub fn prompt_slider_with_step(
+        &mut self,
+        message: &str,
+        min: i64,
+        max: i64,
+        initial: i64,
+        step: i64,
+    ) -> Result<i64, CliPromptError> {
+        if min >= max {
+            return Err(InvalidSliderError {
+                message: "min must be less than max".to_string(),
+            });
+        }
+        if step <= 0 {
+            return Err(InvalidSliderError {
+                message: "step must be positive".to_string(),
+            });
+        }
+
+        let big_step = step.saturating_mul(BIG_STEP_FACTOR);
+        let mut value = initial.clamp(min, max);
+
+        self.term.hide_cursor()?;
+        let frame = self.slider_frame(message, min, max, value, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            let moved = match self.term.read_key()? {
+                Key::Enter => break,
+                Key::ArrowLeft => value.saturating_sub(step),
+                Key::ArrowRight => value.saturating_add(step),
+                Key::PageDown => value.saturating_sub(big_step),
+                Key::PageUp => value.saturating_add(big_step),
+                Key::Home => min,
+                Key::End => max,
+                Key::UnknownEscSeq(sequence) if sequence == ['[', '1', ';'] => {
+                    match self.read_shift_arrow()? {
+                        Some(Key::ArrowLeft) => value.saturating_sub(big_step),
+                        Some(Key::ArrowRight) => value.saturating_add(big_step),
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            value = moved.clamp(min, max);
+
+            let frame = self.slider_frame(message, min, max, value, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.slider_frame(message, min, max, value, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(value)
+    }
+
+    /// Reads the rest of an arrow key with a modifier, like `ESC[1;2C` for Shift+Arrow Right.
+    /// The terminal decodes `ESC[1;` as an unknown sequence, and the modifier and the direction as characters.
+    ///
+    /// Returns the arrow key only if the modifier is Shift.
+    fn read_shift_arrow(&mut self) -> Result<Option<Key>, CliPromptError> {
+        let modifier = self.term.read_key()?;
+        let direction = self.term.read_key()?;
+
+        Ok(match (modifier, direction) {
+            (Key::Char('2'), Key::Char('D')) => Some(Key::ArrowLeft),
+            (Key::Char('2'), Key::Char('C')) => Some(Key::ArrowRight),
+            _ => None,
+        })
+    }
+
+    /// Builds the frame of the slider prompt. Once submitted, only the value is drawn.
+    fn slider_frame(
+        &self,
+        message: &str,
+        min: i64,
+        max: i64,
+        value: i64,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            return vec![question, self.format_bar_line(&value.to_string())];
+        }
+
+        let knob = knob_position(min, max, value);
+        let track = format!(
+            "{}{}{}",
+            self.apply_style(&self.colors.active, self.s_bar_h.repeat(knob)),
+            self.apply_style(&self.colors.active, &self.s_radio_active),
+            self.apply_style(
+                &self.colors.hint,
+                self.s_bar_h.repeat(SLIDER_WIDTH - 1 - knob)
+            ),
+        );
+
+        vec![
+            question,
+            self.format_bar_line(&format!(
+                "{} {} {}  {}",
+                min,
+                track,
+                max,
+                self.apply_style(&self.colors.active, value)
+            )),
+            self.format_hint("left/right to move, page up/down to jump, enter to submit"),
+        ]
+    }
+}
+
+/// Returns the cell index of the knob, from 0 for `min` to `SLIDER_WIDTH - 1` for `max`.
+fn knob_position(min: i64, max: i64, value: i64) -> usize {
+    let offset = i128::from(value) - i128::from(min);
+    let range = i128::from(max) - i128::from(min);
+
+    (offset * (SLIDER_WIDTH as i128 - 1) / range) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    #[test]
+    fn test_knob_position() {
+        assert_eq!(knob_position(0, 100, 0), 0);
+        assert_eq!(knob_position(0, 100, 100), SLIDER_WIDTH - 1);
+        assert_eq!(knob_position(0, 100, 50), 14);
+        assert_eq!(
+            knob_position(i64::MIN, i64::MAX, i64::MAX),
+            SLIDER_WIDTH - 1
+        );
+    }
+
+    #[test]
+    fn test_slider_frame() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+
+        let frame = cli_prompt.slider_frame("message", 0, 29, 3, true);
+
+        assert_eq!(
+            frame[1],
+            format!(
+                "{} 0 {}{}{} 29  3",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar_h").unwrap().repeat(3),
+                prefix_map.get("s_radio_active").unwrap(),
+                prefix_map.get("s_bar_h").unwrap().repeat(26),
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_slider() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&[
+            "arrow right",
+            "arrow right",
+            "arrow left",
+            "page up",
+            "enter",
+        ]);
+
+        let result = cli_prompt.prompt_slider("message", 0, 100, 50).unwrap();
+
+        assert_eq!(result, 61);
+        assert_eq!(
+            format!(
+                "{} message\n{} 61\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_slider_shift_arrow() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&[
+            "esc [1;", "2", "C", "esc [1;", "2", "C", "esc [1;", "2", "D", "esc [1;", "5", "C",
+            "enter",
+        ]);
+
+        assert_eq!(cli_prompt.prompt_slider("message", 0, 100, 50).unwrap(), 60);
+    }
+
+    #[test]
+    fn test_prompt_slider_with_step_clamps() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["page down", "arrow right", "enter"]);
+
+        assert_eq!(
+            cli_prompt
+                .prompt_slider_with_step("message", 10, 200, 500, 5)
+                .unwrap(),
+            155
+        );
+
+        cli_prompt.push_key_inputs(&["end", "page up", "enter"]);
+
+        assert_eq!(
+            cli_prompt
+                .prompt_slider_with_step("message", 10, 200, 10, 5)
+                .unwrap(),
+            200
+        );
+    }
+
+    #[test]
+    fn test_prompt_slider_invalid() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_slider("message", 10, 10, 10),
+            Err(InvalidSliderError { .. })
+        ));
+        assert!(matches!(
+            cli_prompt.prompt_slider_with_step("message", 0, 10, 0, -1),
+            Err(InvalidSliderError { .. })
+        ));
+    }
+}
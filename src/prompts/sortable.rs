@@ -0,0 +1,240 @@
+use crate::cli_prompt_error::CliPromptError::{self, OptionsVecEmptyError};
+use crate::{CliPrompt, Key, MessageType, PromptSelectOption};
+
+impl CliPrompt {
+    /// Prints the prompt message and let users reorder the provided options.
+    /// Users can change the focus by Arrow Up and Arrow Down key, grab the focused option by Space key
+    /// and move the grabbed option by Arrow Up and Arrow Down key. Space key again drops it,
+    /// and Enter key submits the order.
+    ///
+    /// Returns the reordered options as `Vector` of [`PromptSelectOption`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `options` - the list of options in the initial order
+    ///
+    /// # Errors
+    ///
+    /// If `options` is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// ```
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let result = cli_prompt.prompt_sortable("Order the mirrors", vec![]);
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, PromptSelectOption};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let options = vec![
+    ///     PromptSelectOption::new("eu", "Europe mirror"),
+    ///     PromptSelectOption::new("us", "US mirror"),
+    ///     PromptSelectOption::new("asia", "Asia mirror"),
+    /// ];
+    /// let mirrors = cli_prompt.prompt_sortable("Order the mirrors", options).unwrap();
+    /// println!("{:?}", mirrors);
+    /// ```
+    pub fn prompt_sortable(
+        &mut self,
+        message: &str,
+        options: Vec<PromptSelectOption>,
+    ) -> Result<Vec<PromptSelectOption>, CliPromptError> {
+        if options.is_empty() {
+            return Err(OptionsVecEmptyError {
+                message: "options is empty".to_string(),
+            });
+        }
+
+        let mut options = options;
+        let mut choice = 0;
+        let mut is_grabbed = false;
+        let options_num = options.len();
+
+        self.term.hide_cursor()?;
+        let frame = self.sortable_frame(message, &options, choice, is_grabbed, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match self.term.read_key()? {
+                Key::Enter => break,
+                Key::Char(' ') => is_grabbed = !is_grabbed,
+                Key::ArrowUp if is_grabbed => {
+                    if choice == 0 {
+                        continue;
+                    }
+                    options.swap(choice, choice - 1);
+                    choice -= 1;
+                }
+                Key::ArrowDown if is_grabbed => {
+                    if choice == options_num - 1 {
+                        continue;
+                    }
+                    options.swap(choice, choice + 1);
+                    choice += 1;
+                }
+                Key::ArrowUp => choice = (choice + options_num - 1) % options_num,
+                Key::ArrowDown => choice = (choice + 1) % options_num,
+                _ => continue,
+            }
+
+            let frame = self.sortable_frame(message, &options, choice, is_grabbed, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.sortable_frame(message, &options, choice, false, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(options)
+    }
+
+    /// Builds the frame of the sortable prompt. The grabbed option is drawn with the selected color.
+    /// Once submitted, only the labels in the new order are drawn.
+    fn sortable_frame(
+        &self,
+        message: &str,
+        options: &[PromptSelectOption],
+        choice: usize,
+        is_grabbed: bool,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            let labels: Vec<&str> = options.iter().map(|option| option.label.as_str()).collect();
+            return vec![question, self.format_bar_line(&labels.join(", "))];
+        }
+
+        let mut lines = vec![question];
+        for (i, option) in options.iter().enumerate() {
+            if i == choice && is_grabbed {
+                lines.push(
+                    self.format_radio_option(
+                        &self
+                            .apply_style(&self.colors.selected, &option.label)
+                            .to_string(),
+                        true,
+                    ),
+                );
+            } else {
+                lines.push(self.format_radio_option(&option.label, i == choice));
+            }
+        }
+        lines.push(self.format_hint(if is_grabbed {
+            "up/down to move, space to drop"
+        } else {
+            "space to grab, enter to submit"
+        }));
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli_prompt_error::CliPromptError::OptionsVecEmptyError;
+    use crate::tests::build_prefix_map;
+    use crate::{CliPrompt, PromptSelectOption};
+    use console::style;
+
+    fn build_options() -> Vec<PromptSelectOption> {
+        vec![
+            PromptSelectOption::new("a", "first"),
+            PromptSelectOption::new("b", "second"),
+            PromptSelectOption::new("c", "third"),
+        ]
+    }
+
+    fn values(options: &[PromptSelectOption]) -> Vec<&str> {
+        options.iter().map(|option| option.value.as_str()).collect()
+    }
+
+    #[test]
+    fn test_prompt_sortable() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        // grab "first", move it to the bottom, then move "second" down by one
+        cli_prompt.push_key_inputs(&[
+            " ",
+            "arrow down",
+            "arrow down",
+            "arrow down",
+            " ",
+            "arrow down",
+            " ",
+            "arrow down",
+            "enter",
+        ]);
+
+        let result = cli_prompt
+            .prompt_sortable("message", build_options())
+            .unwrap();
+
+        assert_eq!(values(&result), vec!["c", "b", "a"]);
+        assert_eq!(
+            format!(
+                "{} message\n{} third, second, first\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_sortable_focus_wraps() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["arrow up", " ", "arrow up", "enter"]);
+
+        let result = cli_prompt
+            .prompt_sortable("message", build_options())
+            .unwrap();
+
+        assert_eq!(values(&result), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn test_sortable_frame_grabbed() {
+        let prefix_map = build_prefix_map();
+        let cli_prompt = CliPrompt::new();
+
+        let frame = cli_prompt.sortable_frame("message", &build_options(), 1, true, true);
+
+        assert_eq!(
+            frame[2],
+            format!(
+                "{} {} {}",
+                prefix_map.get("s_bar").unwrap(),
+                style(prefix_map.get("s_radio_active").unwrap()).green(),
+                style("second").blue()
+            )
+        );
+        assert_eq!(
+            frame[3],
+            format!(
+                "{} {} third",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_radio_inactive").unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_sortable_empty() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_sortable("message", vec![]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+    }
+}
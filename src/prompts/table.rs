@@ -0,0 +1,439 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidTableError, OptionsVecEmptyError};
+use crate::{CliPrompt, Key, MessageType};
+use console::measure_text_width;
+use std::cmp::Ordering;
+
+/// Maximum number of rows shown at once by [`prompt_table_select`](CliPrompt::prompt_table_select).
+const MAX_VISIBLE_ROWS: usize = 10;
+
+/// Spaces between two columns.
+const COLUMN_GAP: &str = "  ";
+
+impl CliPrompt {
+    /// Prints the prompt message with a table of the provided rows and let users choose one of them.
+    /// The columns are sized to their widest cell.
+    /// Users can change the focus by Arrow Up and Arrow Down key and choose the focused row by Enter key.
+    /// Number keys from `1` sort the rows by that column, and pressing the same key again reverses the order.
+    /// Cells which are numbers come first in numeric order, and digits in the other cells are compared as numbers, like `3d` before `12d`.
+    ///
+    /// Returns the index of the chosen row in `rows`, regardless of the sorting, wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `headers` - the header of each column
+    /// * `rows` - the list of rows, each of which has as many cells as `headers`
+    ///
+    /// # Errors
+    ///
+    /// If `rows` is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// If `headers` is empty or a row has a different number of cells, [`InvalidTableError`](InvalidTableError) will be returned.
+    /// ```
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let rows = vec![vec!["api".to_string()]];
+    ///
+    /// let result = cli_prompt.prompt_table_select("message", &["name", "version"], &rows);
+    ///
+    /// assert!(result.is_err());
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.to_string(), "every row must have as many cells as headers");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let deployments: Vec<Vec<String>> = vec![
+    ///     vec!["api", "1.4.2", "3d", "running"],
+    ///     vec!["worker", "1.4.0", "12d", "degraded"],
+    /// ]
+    /// .into_iter()
+    /// .map(|row| row.into_iter().map(String::from).collect())
+    /// .collect();
+    ///
+    /// let index = cli_prompt
+    ///     .prompt_table_select("Pick a deployment", &["name", "version", "age", "status"], &deployments)
+    ///     .unwrap();
+    /// println!("{:?}", deployments[index]);
+    /// ```
+    pub fn prompt_table_select(
+        &mut self,
+        message: &str,
+        headers: &[&str],
+        rows: &[Vec<String>],
+    ) -> Result<usize, CliPromptError> {
+        if rows.is_empty() {
+            return Err(OptionsVecEmptyError {
+                message: "rows is empty".to_string(),
+            });
+        }
+
+        if headers.is_empty() {
+            return Err(InvalidTableError {
+                message: "headers is empty".to_string(),
+            });
+        }
+
+        if rows.iter().any(|row| row.len() != headers.len()) {
+            return Err(InvalidTableError {
+                message: "every row must have as many cells as headers".to_string(),
+            });
+        }
+
+        let mut state = TableState {
+            order: (0..rows.len()).collect(),
+            sort: None,
+            choice: 0,
+        };
+
+        self.term.hide_cursor()?;
+        let frame = self.table_frame(message, headers, rows, &state, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match self.term.read_key()? {
+                Key::Enter => break,
+                Key::ArrowUp => state.choice = (state.choice + rows.len() - 1) % rows.len(),
+                Key::ArrowDown => state.choice = (state.choice + 1) % rows.len(),
+                Key::Char(c @ '1'..='9') => {
+                    let column = c as usize - '1' as usize;
+                    if column >= headers.len() {
+                        continue;
+                    }
+                    state.sort_by_column(rows, column);
+                }
+                _ => continue,
+            }
+
+            let frame = self.table_frame(message, headers, rows, &state, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.table_frame(message, headers, rows, &state, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(state.order[state.choice])
+    }
+
+    /// Builds the frame of the table select prompt. The sorted column is marked with `^` or `v`.
+    /// Once submitted, only the cells of the chosen row are drawn.
+    fn table_frame(
+        &self,
+        message: &str,
+        headers: &[&str],
+        rows: &[Vec<String>],
+        state: &TableState,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let (order, choice) = (&state.order, state.choice);
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            return vec![
+                question,
+                self.format_bar_line(&rows[order[choice]].join(COLUMN_GAP)),
+            ];
+        }
+
+        let headers: Vec<String> = headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| match state.sort {
+                Some((column, true)) if column == i => format!("{} ^", header),
+                Some((column, false)) if column == i => format!("{} v", header),
+                _ => header.to_string(),
+            })
+            .collect();
+
+        let mut widths: Vec<usize> = headers.iter().map(|h| measure_text_width(h)).collect();
+        for row in rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(measure_text_width(cell));
+            }
+        }
+
+        let header_line = format!(
+            "{} {}",
+            " ".repeat(measure_text_width(&self.s_radio_inactive)),
+            align_cells(&headers, &widths)
+        );
+        let mut frame = vec![question, self.format_hint(&header_line)];
+
+        let start = (choice + 1).saturating_sub(MAX_VISIBLE_ROWS);
+        for (i, &row) in order.iter().enumerate().skip(start).take(MAX_VISIBLE_ROWS) {
+            let line = align_cells(&rows[row], &widths);
+            if i == choice {
+                frame.push(self.format_radio_option(
+                    &self.apply_style(&self.colors.active, line).to_string(),
+                    true,
+                ));
+            } else {
+                frame.push(self.format_radio_option(&line, false));
+            }
+        }
+
+        if rows.len() > MAX_VISIBLE_ROWS {
+            frame.push(self.format_hint(&format!("({}/{})", choice + 1, rows.len())));
+        }
+        frame.push(self.format_hint(&format!(
+            "1-{} to sort by column, enter to select",
+            headers.len().min(9)
+        )));
+
+        frame
+    }
+}
+
+/// Display order of the rows, with the sorted column and the focused position in `order`.
+struct TableState {
+    order: Vec<usize>,
+    /// The sorted column and whether it is in ascending order
+    sort: Option<(usize, bool)>,
+    choice: usize,
+}
+
+impl TableState {
+    /// Sorts the rows by `column`, reversing the order if it is already sorted by `column` in ascending order.
+    /// The focus stays on the same row.
+    fn sort_by_column(&mut self, rows: &[Vec<String>], column: usize) {
+        let is_ascending =
+            !matches!(self.sort, Some((sorted_column, true)) if sorted_column == column);
+        let focused_row = self.order[self.choice];
+
+        self.order
+            .sort_by(|&a, &b| compare_cells(&rows[a][column], &rows[b][column]));
+        if !is_ascending {
+            self.order.reverse();
+        }
+        self.sort = Some((column, is_ascending));
+        self.choice = self
+            .order
+            .iter()
+            .position(|&i| i == focused_row)
+            .unwrap_or(0);
+    }
+}
+
+/// Pads every cell to the width of its column by display width, without padding the last one.
+fn align_cells<S: AsRef<str>>(cells: &[S], widths: &[usize]) -> String {
+    let last = cells.len() - 1;
+    cells
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (cell, &width))| {
+            let cell = cell.as_ref();
+            if i == last {
+                cell.to_string()
+            } else {
+                format!("{}{}", cell, " ".repeat(width - measure_text_width(cell)))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(COLUMN_GAP)
+}
+
+/// Compares cells so that numbers come first in numeric order, followed by the other cells in natural order,
+/// where digit runs are compared as numbers like `3d` < `12d`.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (parse_number(a), parse_number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y).then_with(|| a.cmp(b)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => natural_chunks(a)
+            .cmp(natural_chunks(b))
+            .then_with(|| a.cmp(b)),
+    }
+}
+
+/// Parses the cell if it is digits with an optional sign and decimal point, like `-1.5`.
+/// Unlike `f64::from_str`, words like `nan` and `inf` and exponents like `1e5` are not numbers.
+fn parse_number(cell: &str) -> Option<f64> {
+    let cell = cell.trim();
+    let unsigned = cell.strip_prefix(['+', '-']).unwrap_or(cell);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+
+    if integer.len() + fraction.len() == 0 || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    cell.parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+/// A run of digits or of other characters, digit runs ordered first.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum NaturalChunk<'a> {
+    /// Digits without leading zeros, ordered by the length and then the digits, which is the numeric order
+    Number(usize, &'a str),
+    Text(&'a str),
+}
+
+fn natural_chunks(text: &str) -> impl Iterator<Item = NaturalChunk<'_>> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != first.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+
+        Some(if first.is_ascii_digit() {
+            let digits = chunk.trim_start_matches('0');
+            NaturalChunk::Number(digits.len(), digits)
+        } else {
+            NaturalChunk::Text(chunk)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_rows() -> Vec<Vec<String>> {
+        vec![
+            vec!["api", "12", "running"],
+            vec!["ワーカー", "3", "degraded"],
+            vec!["cron", "100", "stopped"],
+        ]
+        .into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect()
+    }
+
+    #[test]
+    fn test_align_cells() {
+        assert_eq!(align_cells(&["ワーカー", "3"], &[8, 3]), "ワーカー  3");
+        assert_eq!(align_cells(&["api", "12"], &[8, 3]), "api       12");
+    }
+
+    #[test]
+    fn test_compare_cells() {
+        assert_eq!(compare_cells("3", "12"), Ordering::Less);
+        assert_eq!(compare_cells("3d", "12d"), Ordering::Less);
+        assert_eq!(compare_cells("1.5", "1.25"), Ordering::Greater);
+        assert_eq!(compare_cells("v1.10", "v1.9"), Ordering::Greater);
+        assert_eq!(compare_cells("007", "7"), Ordering::Less);
+        assert_eq!(compare_cells("-2", "1"), Ordering::Less);
+        assert_eq!(compare_cells(".5", "1."), Ordering::Less);
+
+        // words parsed by `f64::from_str` are text
+        assert_eq!(compare_cells("10", "inf"), Ordering::Less);
+        assert_eq!(compare_cells("nan", "infinity"), Ordering::Greater);
+        assert_eq!(compare_cells("1e5", "2"), Ordering::Greater);
+
+        // numbers come before the other cells, so that the order has no cycle
+        assert_eq!(compare_cells("9", "10"), Ordering::Less);
+        assert_eq!(compare_cells("10", "1a"), Ordering::Less);
+        assert_eq!(compare_cells("9", "1a"), Ordering::Less);
+
+        let mut cells = vec!["1a", "10", "b", "9", "12d", "a", "3d", "1.5"];
+        cells.sort_by(|a, b| compare_cells(a, b));
+        assert_eq!(cells, vec!["1.5", "9", "10", "1a", "3d", "12d", "a", "b"]);
+    }
+
+    #[test]
+    fn test_table_frame() {
+        let prefix_map = build_prefix_map();
+        let rows = build_rows();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+
+        let state = TableState {
+            order: vec![1, 0, 2],
+            sort: Some((1, true)),
+            choice: 1,
+        };
+
+        let frame =
+            cli_prompt.table_frame("message", &["name", "age", "status"], &rows, &state, true);
+        let bar = prefix_map.get("s_bar").unwrap();
+        let padding = " ".repeat(measure_text_width(
+            prefix_map.get("s_radio_inactive").unwrap(),
+        ));
+
+        assert_eq!(
+            frame[1],
+            format!("{} {} name      age ^  status", bar, padding)
+        );
+        assert_eq!(
+            frame[2],
+            format!(
+                "{} {} ワーカー  3      degraded",
+                bar,
+                prefix_map.get("s_radio_inactive").unwrap()
+            )
+        );
+        assert_eq!(
+            frame[3],
+            format!(
+                "{} {} api       12     running",
+                bar,
+                prefix_map.get("s_radio_active").unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_table_select() {
+        let prefix_map = build_prefix_map();
+        let rows = build_rows();
+        let mut cli_prompt = CliPrompt::new();
+        // sorting by age descending keeps the focus on the first row
+        cli_prompt.push_key_inputs(&["2", "2", "enter"]);
+
+        let result = cli_prompt
+            .prompt_table_select("message", &["name", "age", "status"], &rows)
+            .unwrap();
+
+        assert_eq!(result, 0);
+        assert_eq!(
+            format!(
+                "{} message\n{} api  12  running\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_table_select_keeps_focus_on_sort() {
+        let rows = build_rows();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["arrow up", "1", "9", "enter"]);
+
+        let result = cli_prompt
+            .prompt_table_select("message", &["name", "age", "status"], &rows)
+            .unwrap();
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_prompt_table_select_invalid() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_table_select("message", &["name"], &[]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+        assert!(matches!(
+            cli_prompt.prompt_table_select("message", &[], &build_rows()),
+            Err(InvalidTableError { .. })
+        ));
+    }
+}
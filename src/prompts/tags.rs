@@ -0,0 +1,363 @@
+use crate::cli_prompt_error::CliPromptError;
+use crate::line_buffer::LineBuffer;
+use crate::prompts::{edit_line, Validator};
+use crate::{CliPrompt, Key, MessageType};
+
+/// Maximum number of suggestions shown at once by [`prompt_tags_with_options`](CliPrompt::prompt_tags_with_options).
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Suggestions and validation of [`prompt_tags_with_options`](CliPrompt::prompt_tags_with_options).
+///
+/// # Example
+/// ```no_run
+/// use cli_prompts_rs::{CliPrompt, TagsOptions};
+///
+/// let options = TagsOptions {
+///     suggestions: vec!["web-1".to_string(), "web-2".to_string(), "db-1".to_string()],
+///     validator: Some(Box::new(|host| {
+///         if host.contains(' ') {
+///             Err("hostnames can't contain spaces".to_string())
+///         } else {
+///             Ok(())
+///         }
+///     })),
+/// };
+///
+/// let mut cli_prompt = CliPrompt::new();
+/// let hosts = cli_prompt.prompt_tags_with_options("Hosts", options).unwrap();
+/// ```
+#[derive(Default)]
+pub struct TagsOptions {
+    /// Values suggested while typing. Those starting with the input are listed.
+    pub suggestions: Vec<String>,
+    /// Checks each value before it is added. The error message is shown below the input.
+    pub validator: Option<Validator>,
+}
+
+struct TagsState {
+    tags: Vec<String>,
+    buffer: LineBuffer,
+    /// Index of the highlighted suggestion among the matching ones
+    suggestion: usize,
+    error: Option<String>,
+}
+
+impl TagsState {
+    /// Lists the suggestions starting with the input, except the values already added.
+    fn matching_suggestions<'a>(&self, options: &'a TagsOptions) -> Vec<&'a str> {
+        let input = self.buffer.text().trim().to_lowercase();
+        options
+            .suggestions
+            .iter()
+            .filter(|suggestion| suggestion.to_lowercase().starts_with(&input))
+            .filter(|suggestion| !self.tags.contains(suggestion))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Adds `value` if it is neither a duplicate nor rejected by the validator.
+    fn commit(&mut self, value: &str, options: &TagsOptions) {
+        let value = value.trim();
+
+        self.error = if self.tags.iter().any(|tag| tag == value) {
+            Some(format!("\"{}\" is already added", value))
+        } else {
+            options
+                .validator
+                .as_ref()
+                .and_then(|validator| validator(value).err())
+        };
+
+        if self.error.is_none() {
+            self.tags.push(value.to_string());
+            self.buffer = LineBuffer::default();
+            self.suggestion = 0;
+        }
+    }
+}
+
+impl CliPrompt {
+    /// Prints the prompt message and let users enter a list of values.
+    /// Enter key or comma adds the typed value, and Backspace key on the empty input removes the last one.
+    /// Duplicated values are rejected. Enter key on the empty input submits the list.
+    ///
+    /// Returns the values in the order they are added as `Vector` of `String` wrapped in `Result`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_tags_with_options`] with default options.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let labels = cli_prompt.prompt_tags("Labels").unwrap();
+    /// println!("{:?}", labels);
+    /// ```
+    pub fn prompt_tags(&mut self, message: &str) -> Result<Vec<String>, CliPromptError> {
+        self.prompt_tags_with_options(message, TagsOptions::default())
+    }
+
+    /// Prints the prompt message and let users enter a list of values, with suggestions and validation.
+    /// Users can change the highlighted suggestion by Arrow Up and Arrow Down key and add it by Tab key.
+    /// A value rejected by [`TagsOptions::validator`] isn't added, and its error message is shown instead.
+    /// The other keys are same as [`CliPrompt::prompt_tags`].
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `options` - the suggestions and the validator
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, TagsOptions};
+    ///
+    /// let options = TagsOptions {
+    ///     suggestions: vec!["bug".to_string(), "feature".to_string(), "docs".to_string()],
+    ///     ..TagsOptions::default()
+    /// };
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let labels = cli_prompt.prompt_tags_with_options("Labels", options).unwrap();
+    /// println!("{:?}", labels);
+    /// ```
+    pub fn prompt_tags_with_options(
+        &mut self,
+        message: &str,
+        options: TagsOptions,
+    ) -> Result<Vec<String>, CliPromptError> {
+        let mut state = TagsState {
+            tags: Vec::new(),
+            buffer: LineBuffer::default(),
+            suggestion: 0,
+            error: None,
+        };
+
+        self.term.hide_cursor()?;
+        let frame = self.tags_frame(message, &state, &options, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            let key = self.term.read_key()?;
+            let input = state.buffer.text();
+
+            match key {
+                Key::Enter if input.trim().is_empty() => break,
+                Key::Enter | Key::Char(',') => {
+                    if input.trim().is_empty() {
+                        continue;
+                    }
+                    state.commit(&input, &options);
+                }
+                Key::Backspace if input.is_empty() => {
+                    if state.tags.pop().is_none() {
+                        continue;
+                    }
+                    state.error = None;
+                }
+                Key::Tab => match state.matching_suggestions(&options).get(state.suggestion) {
+                    Some(suggestion) => state.commit(suggestion, &options),
+                    None => continue,
+                },
+                Key::ArrowUp | Key::ArrowDown => {
+                    let len = state.matching_suggestions(&options).len();
+                    if len == 0 {
+                        continue;
+                    }
+                    state.suggestion = if key == Key::ArrowUp {
+                        (state.suggestion + len - 1) % len
+                    } else {
+                        (state.suggestion + 1) % len
+                    };
+                }
+                key => {
+                    if !edit_line(&mut state.buffer, &key) {
+                        continue;
+                    }
+                    state.suggestion = 0;
+                    state.error = None;
+                }
+            }
+
+            let frame = self.tags_frame(message, &state, &options, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.tags_frame(message, &state, &options, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(state.tags)
+    }
+
+    /// Builds the frame of the tags prompt. The values are drawn as chips in front of the input.
+    /// Once submitted, only the values are drawn.
+    fn tags_frame(
+        &self,
+        message: &str,
+        state: &TagsState,
+        options: &TagsOptions,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            return vec![question, self.format_bar_line(&state.tags.join(", "))];
+        }
+
+        let mut line = String::new();
+        for tag in &state.tags {
+            line.push_str(
+                &self
+                    .apply_style(&self.colors.selected, format!("[{}]", tag))
+                    .to_string(),
+            );
+            line.push(' ');
+        }
+        line.push_str(&self.format_cursor_line(&state.buffer));
+
+        let mut frame = vec![question, self.format_bar_line(&line)];
+
+        if let Some(error) = &state.error {
+            frame.push(
+                self.format_bar_line(&self.apply_style(&self.colors.error, error).to_string()),
+            );
+        }
+
+        let suggestions = state.matching_suggestions(options);
+        let start = (state.suggestion + 1).saturating_sub(MAX_SUGGESTIONS);
+        for (i, suggestion) in suggestions
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MAX_SUGGESTIONS)
+        {
+            frame.push(self.format_radio_option(suggestion, i == state.suggestion));
+        }
+
+        frame.push(self.format_hint(if suggestions.is_empty() {
+            "enter or comma to add, enter on empty input to submit"
+        } else {
+            "enter or comma to add, tab to add suggestion, enter on empty input to submit"
+        }));
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    #[test]
+    fn test_prompt_tags() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["a", "p", "i", ",", " ", "w", "e", "b", "enter", "enter"]);
+
+        let result = cli_prompt.prompt_tags("message").unwrap();
+
+        assert_eq!(result, vec!["api", "web"]);
+        assert_eq!(
+            format!(
+                "{} message\n{} api, web\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_tags_backspace_and_duplicates() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&[
+            "a",
+            "enter",
+            "b",
+            "enter",
+            "a",
+            "enter",
+            "backspace",
+            "backspace",
+            "c",
+            "enter",
+            "enter",
+        ]);
+
+        let result = cli_prompt.prompt_tags("message").unwrap();
+
+        assert_eq!(result, vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_prompt_tags_with_options() {
+        let mut cli_prompt = CliPrompt::new();
+        let options = TagsOptions {
+            suggestions: vec!["web-1".to_string(), "web-2".to_string(), "db-1".to_string()],
+            validator: Some(Box::new(|value| {
+                if value.contains('!') {
+                    Err("invalid".to_string())
+                } else {
+                    Ok(())
+                }
+            })),
+        };
+        cli_prompt.push_key_inputs(&[
+            "w",
+            "arrow down",
+            "tab",
+            "tab",
+            "x",
+            "!",
+            "enter",
+            "backspace",
+            "enter",
+            "enter",
+        ]);
+
+        let result = cli_prompt
+            .prompt_tags_with_options("message", options)
+            .unwrap();
+
+        assert_eq!(result, vec!["web-2", "web-1", "x"]);
+    }
+
+    #[test]
+    fn test_tags_frame() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let options = TagsOptions {
+            suggestions: vec!["api".to_string(), "app".to_string()],
+            ..TagsOptions::default()
+        };
+        let mut state = TagsState {
+            tags: vec!["api".to_string()],
+            buffer: LineBuffer::new("a"),
+            suggestion: 0,
+            error: None,
+        };
+        state.commit("api", &options);
+
+        let frame = cli_prompt.tags_frame("message", &state, &options, true);
+        let bar = prefix_map.get("s_bar").unwrap();
+
+        assert_eq!(frame[1], format!("{} [api] a ", bar));
+        assert_eq!(frame[2], format!("{} \"api\" is already added", bar));
+        assert_eq!(
+            frame[3],
+            format!("{} {} app", bar, prefix_map.get("s_radio_active").unwrap())
+        );
+    }
+}
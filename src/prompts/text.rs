@@ -0,0 +1,813 @@
+use crate::cli_prompt_error::CliPromptError;
+use crate::history::History;
+use crate::line_buffer::LineBuffer;
+use crate::prompts::{edit_line, mask, CTRL_R};
+use crate::{CliPrompt, Key, MessageType};
+use std::io::Write;
+
+impl CliPrompt {
+    /// Prints the prompt message and read user's input
+    ///
+    /// The input can be edited with the following keys, and Enter key submits it.
+    /// - Arrow Left / Arrow Right: move the cursor
+    /// - Home / End (Ctrl+A / Ctrl+E): move the cursor to the start / end
+    /// - Backspace / Del: delete the character before / under the cursor
+    /// - Ctrl+W: delete the word before the cursor
+    /// - Ctrl+U / Ctrl+K: delete the text before / after the cursor
+    ///
+    /// When stdin is not a terminal, such as piped input, a line is read without editing.
+    ///
+    /// Returns the input as `String` wrapped in `Result`
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let answer = cli_prompt.prompt_text("example app").unwrap();
+    /// println!("{}", answer);
+    /// ```
+    pub fn prompt_text(&mut self, message: &str) -> Result<String, CliPromptError> {
+        self.read_text(message, TextOptions::default())
+    }
+
+    /// Prints the prompt message and read user's input, recalling the previous inputs of the same `history_key`.
+    ///
+    /// On top of the editing keys of [`CliPrompt::prompt_text`],
+    /// - Arrow Up / Arrow Down: recall the previous / next entry
+    /// - Ctrl+R: search the entries containing the typed text. Ctrl+R again finds an older match,
+    ///   Enter takes the match and Escape leaves the search.
+    ///
+    /// The submitted input is saved to a file named after `history_key`
+    /// in [the history directory](CliPrompt::set_history_dir). Saving is best-effort,
+    /// so a history file that can't be written doesn't fail the prompt.
+    ///
+    /// Returns the input as `String` wrapped in `Result`
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `history_key` - the name of the history, like `hosts`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let host = cli_prompt.prompt_text_with_history("Host", "hosts").unwrap();
+    /// println!("{}", host);
+    /// ```
+    pub fn prompt_text_with_history(
+        &mut self,
+        message: &str,
+        history_key: &str,
+    ) -> Result<String, CliPromptError> {
+        let history = match &self.history_dir {
+            Some(dir) if self.history_size > 0 => {
+                Some(History::load(dir, history_key, self.history_size))
+            }
+            _ => None,
+        };
+
+        self.read_text(
+            message,
+            TextOptions {
+                history,
+                ..TextOptions::default()
+            },
+        )
+    }
+
+    /// Prints the prompt message and read user's input, completing it with `completer` on Tab key.
+    ///
+    /// `completer` takes the current input and returns the candidates, each of which replaces the whole input.
+    /// On Tab key, a single candidate is taken as is. Otherwise the input is completed to
+    /// the common prefix of the candidates, which are shown dimmed below the input line,
+    /// and the next Tab / Shift+Tab keys cycle through them.
+    /// The input supports the editing keys of [`CliPrompt::prompt_text`].
+    ///
+    /// Returns the input as `String` wrapped in `Result`
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `completer` - the function returning the candidates for the input,
+    ///   like [`path_completer`](crate::completion::path_completer)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::completion::path_completer;
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let config = cli_prompt
+    ///     .prompt_text_with_completer("Config file", path_completer)
+    ///     .unwrap();
+    ///
+    /// let environments = ["development", "staging", "production"];
+    /// let environment = cli_prompt
+    ///     .prompt_text_with_completer("Environment", |input| {
+    ///         environments
+    ///             .iter()
+    ///             .filter(|environment| environment.starts_with(input))
+    ///             .map(|environment| environment.to_string())
+    ///             .collect()
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn prompt_text_with_completer<F>(
+        &mut self,
+        message: &str,
+        completer: F,
+    ) -> Result<String, CliPromptError>
+    where
+        F: Fn(&str) -> Vec<String>,
+    {
+        self.read_text(
+            message,
+            TextOptions {
+                completer: Some(&completer),
+                ..TextOptions::default()
+            },
+        )
+    }
+
+    /// Prints the prompt message and read user's input without echoing it.
+    /// Each typed character is printed as the password mask symbol of the [`Theme`](crate::theme::Theme).
+    ///
+    /// The input supports the editing keys of [`CliPrompt::prompt_text`], and is never recorded to history.
+    ///
+    /// Returns the input as `String` wrapped in `Result`. Unlike [`CliPrompt::prompt_text`], the input is not trimmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let password = cli_prompt.prompt_password("Password").unwrap();
+    /// ```
+    pub fn prompt_password(&mut self, message: &str) -> Result<String, CliPromptError> {
+        self.read_text(
+            message,
+            TextOptions {
+                is_masked: true,
+                ..TextOptions::default()
+            },
+        )
+    }
+
+    fn read_text(&mut self, message: &str, options: TextOptions) -> Result<String, CliPromptError> {
+        let mut state = TextState::default();
+
+        // keys can't be read without a terminal, so piped input is read line by line
+        if !self.term.is_term() {
+            self.term
+                .write_line(&self.format_prefix(message.to_string(), MessageType::Question))?;
+            self.term.write_all(format!("{} ", self.s_bar).as_bytes())?;
+            let line = if options.is_masked {
+                self.term.read_secure_line()?
+            } else {
+                self.term.read_line()?
+            };
+            state.buffer = LineBuffer::new(&line);
+            self.print_empty_line()?;
+            return self.finish_text(state, options);
+        }
+
+        self.term.hide_cursor()?;
+        let frame = self.text_frame(message, &state, &options, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            let key = self.term.read_key()?;
+            let is_updated = match (&mut state.search, &options.history) {
+                (Some(search), Some(history)) => {
+                    if let Some(found) = handle_search_key(search, history, &key) {
+                        state.buffer = LineBuffer::new(&found);
+                        state.history_index = None;
+                    }
+                    if matches!(key, Key::Enter | Key::Escape) {
+                        state.search = None;
+                    }
+                    true
+                }
+                _ if key == Key::Enter => break,
+                _ => handle_text_key(&mut state, &options, &key),
+            };
+
+            if is_updated {
+                let frame = self.text_frame(message, &state, &options, true);
+                height = self.draw_frame(&frame, height)?;
+            }
+        }
+
+        let frame = self.text_frame(message, &state, &options, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        self.finish_text(state, options)
+    }
+
+    /// Returns the text typed into the state, recording it to the history unless masked.
+    fn finish_text(
+        &mut self,
+        state: TextState,
+        mut options: TextOptions,
+    ) -> Result<String, CliPromptError> {
+        if options.is_masked {
+            return Ok(state.buffer.text());
+        }
+
+        let text = state.buffer.text().trim().to_string();
+        if let Some(history) = options.history.as_mut() {
+            history.push(&text);
+            let _ = history.save();
+        }
+
+        Ok(text)
+    }
+
+    /// Builds the frame of the text prompts. The cursor, the history search and the candidates are drawn only while `is_editing`.
+    fn text_frame(
+        &self,
+        message: &str,
+        state: &TextState,
+        options: &TextOptions,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let mut frame = vec![self.format_prefix(message.to_string(), MessageType::Question)];
+
+        let masked;
+        let buffer = if options.is_masked {
+            masked = mask(&state.buffer, &self.s_password_mask);
+            &masked
+        } else {
+            &state.buffer
+        };
+
+        if !is_editing {
+            frame.push(self.format_bar_line(&buffer.text()));
+            return frame;
+        }
+
+        if let (Some(search), Some(history)) = (&state.search, &options.history) {
+            let found = search.index.map(|index| history.entries()[index].as_str());
+            frame.push(self.format_bar_line(found.unwrap_or_default()));
+            frame.push(self.format_hint(&match found {
+                Some(_) => format!("(reverse-i-search) `{}`", search.query),
+                None => format!("(failed reverse-i-search) `{}`", search.query),
+            }));
+            return frame;
+        }
+
+        frame.push(self.format_bar_line(&self.format_cursor_line(buffer)));
+
+        if let Some(completion) = &state.completion {
+            let selected = completion.index.unwrap_or(0);
+            let start = (selected + 1).saturating_sub(MAX_CANDIDATES);
+            for (i, candidate) in completion
+                .candidates
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(MAX_CANDIDATES)
+            {
+                let style = if completion.index == Some(i) {
+                    &self.colors.active
+                } else {
+                    &self.colors.hint
+                };
+                frame.push(
+                    self.format_bar_line(&format!("  {}", self.apply_style(style, candidate))),
+                );
+            }
+            if completion.candidates.len() > MAX_CANDIDATES {
+                frame.push(self.format_hint(&format!(
+                    "  ({}/{} candidates)",
+                    selected + 1,
+                    completion.candidates.len()
+                )));
+            }
+        }
+
+        frame
+    }
+}
+
+/// Maximum number of completion candidates shown below the input line.
+const MAX_CANDIDATES: usize = 8;
+
+type Completer<'a> = &'a dyn Fn(&str) -> Vec<String>;
+
+/// Options of the text prompts.
+#[derive(Default)]
+struct TextOptions<'a> {
+    history: Option<History>,
+    completer: Option<Completer<'a>>,
+    is_masked: bool,
+}
+
+/// State of the text prompts while users type.
+#[derive(Default)]
+struct TextState {
+    buffer: LineBuffer,
+    /// `None` while editing the draft, otherwise the index of the recalled entry
+    history_index: Option<usize>,
+    draft: String,
+    search: Option<HistorySearch>,
+    completion: Option<Completion>,
+}
+
+struct HistorySearch {
+    query: String,
+    /// Index of the entry matching `query`
+    index: Option<usize>,
+}
+
+struct Completion {
+    candidates: Vec<String>,
+    /// Index of the candidate Tab key cycled to
+    index: Option<usize>,
+}
+
+/// Applies the key to the history search. Returns the found entry when Enter key takes it.
+fn handle_search_key(search: &mut HistorySearch, history: &History, key: &Key) -> Option<String> {
+    let entries_len = history.entries().len();
+    match key {
+        Key::Char(CTRL_R) => {
+            if let Some(found) = search
+                .index
+                .and_then(|index| history.search(&search.query, index))
+            {
+                search.index = Some(found);
+            }
+        }
+        Key::Backspace => {
+            search.query.pop();
+            search.index = history.search(&search.query, entries_len);
+        }
+        Key::Char(c) if !c.is_control() => {
+            search.query.push(*c);
+            search.index = history.search(&search.query, entries_len);
+        }
+        Key::Enter => {
+            return search
+                .index
+                .map(|index| history.entries()[index].to_string())
+        }
+        _ => {}
+    }
+
+    None
+}
+
+/// Applies the key to the input. Returns false if the key is ignored.
+fn handle_text_key(state: &mut TextState, options: &TextOptions, key: &Key) -> bool {
+    match (key, &options.history, options.completer) {
+        (Key::ArrowUp, Some(history), _) => {
+            let index = state.history_index.unwrap_or(history.entries().len());
+            if index == 0 {
+                return false;
+            }
+            if state.history_index.is_none() {
+                state.draft = state.buffer.text();
+            }
+            state.history_index = Some(index - 1);
+            state.buffer = LineBuffer::new(&history.entries()[index - 1]);
+        }
+        (Key::ArrowDown, Some(history), _) => {
+            let Some(index) = state.history_index else {
+                return false;
+            };
+            if index + 1 < history.entries().len() {
+                state.history_index = Some(index + 1);
+                state.buffer = LineBuffer::new(&history.entries()[index + 1]);
+            } else {
+                state.history_index = None;
+                state.buffer = LineBuffer::new(&state.draft);
+            }
+        }
+        (Key::Char(CTRL_R), Some(_), _) => {
+            state.search = Some(HistorySearch {
+                query: String::new(),
+                index: None,
+            });
+        }
+        (Key::Tab | Key::BackTab, _, Some(completer)) => return complete(state, completer, key),
+        _ => {
+            if !edit_line(&mut state.buffer, key) {
+                return false;
+            }
+        }
+    }
+    state.completion = None;
+
+    true
+}
+
+/// Completes the input on Tab key. A single candidate replaces the input,
+/// otherwise the common prefix of the candidates does, and the next Tab keys cycle through the candidates.
+fn complete(state: &mut TextState, completer: Completer, key: &Key) -> bool {
+    if let Some(completion) = state.completion.as_mut() {
+        let len = completion.candidates.len();
+        let index = match (completion.index, key) {
+            (None, Key::BackTab) => len - 1,
+            (None, _) => 0,
+            (Some(index), Key::BackTab) => (index + len - 1) % len,
+            (Some(index), _) => (index + 1) % len,
+        };
+        completion.index = Some(index);
+        state.buffer = LineBuffer::new(&completion.candidates[index]);
+
+        return true;
+    }
+
+    let input = state.buffer.text();
+    let candidates = completer(&input);
+    match candidates.len() {
+        0 => return false,
+        1 => state.buffer = LineBuffer::new(&candidates[0]),
+        _ => {
+            let prefix = common_prefix(&candidates);
+            if prefix.chars().count() > input.chars().count() {
+                state.buffer = LineBuffer::new(&prefix);
+            }
+            state.completion = Some(Completion {
+                candidates,
+                index: None,
+            });
+        }
+    }
+
+    true
+}
+
+fn common_prefix(candidates: &[String]) -> String {
+    let mut prefix: Vec<char> = candidates[0].chars().collect();
+    for candidate in &candidates[1..] {
+        let len = prefix
+            .iter()
+            .zip(candidate.chars())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        prefix.truncate(len);
+    }
+
+    prefix.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::get_symbol;
+    use crate::tests::build_prefix_map;
+    use console::style;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use supports_unicode::Stream;
+
+    #[test]
+    fn test_prompt_text() {
+        let prefix_map = build_prefix_map();
+
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["m", "y", " ", "n", "a", "m", "e", "enter"]);
+
+        let result = cli_prompt.prompt_text("name?").unwrap();
+
+        assert_eq!(
+            format!(
+                "{} name?\n{} my name\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+        assert_eq!(result, "my name".to_string());
+    }
+
+    #[test]
+    fn test_prompt_text_without_terminal() {
+        let prefix_map = build_prefix_map();
+
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.set_term_input("my name\n");
+
+        let result = cli_prompt.prompt_text("name?").unwrap();
+
+        assert_eq!(
+            format!(
+                "{} name?\n{} {}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+        assert_eq!(result, "my name".to_string());
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_term_input(" secret ");
+
+        let result = cli_prompt.prompt_password("password?").unwrap();
+
+        assert_eq!(result, " secret ".to_string());
+    }
+
+    #[test]
+    fn test_prompt_text_cursor_movement() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&[
+            "b",
+            "c",
+            "home",
+            "a",
+            "end",
+            "d",
+            "arrow left",
+            "arrow left",
+            "del",
+            "enter",
+        ]);
+
+        let result = cli_prompt.prompt_text("message").unwrap();
+
+        assert_eq!(result, "abd");
+    }
+
+    #[test]
+    fn test_prompt_text_delete_keys() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&[
+            "f",
+            "o",
+            "o",
+            " ",
+            "b",
+            "a",
+            "r",
+            "\x17",
+            "b",
+            "a",
+            "z",
+            "arrow left",
+            "\x0b",
+            "home",
+            "arrow right",
+            "\x15",
+            "enter",
+        ]);
+
+        let result = cli_prompt.prompt_text("message").unwrap();
+
+        assert_eq!(result, "oo ba");
+    }
+
+    #[test]
+    fn test_prompt_text_wide_characters() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["한", "글", "arrow left", "backspace", "字", "enter"]);
+
+        let result = cli_prompt.prompt_text("message").unwrap();
+
+        assert_eq!(result, "字글");
+        assert!(cli_prompt
+            .term
+            .get_output_string()
+            .contains(&format!("{} 字글\n", prefix_map.get("s_bar").unwrap())));
+    }
+
+    fn history_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("cli_prompts_rs-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_prompt_text_with_history_recall() {
+        let dir = history_dir("text-recall");
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_history_dir(&dir);
+
+        for host in ["alpha", "beta"] {
+            for c in host.chars() {
+                cli_prompt.push_key_input(&c.to_string());
+            }
+            cli_prompt.push_key_input("enter");
+            cli_prompt
+                .prompt_text_with_history("host", "hosts")
+                .unwrap();
+        }
+
+        cli_prompt.push_key_inputs(&["g", "arrow up", "arrow up", "arrow up", "enter"]);
+        assert_eq!(
+            cli_prompt
+                .prompt_text_with_history("host", "hosts")
+                .unwrap(),
+            "alpha"
+        );
+
+        cli_prompt.push_key_inputs(&[
+            "g",
+            "arrow up",
+            "arrow up",
+            "arrow down",
+            "arrow down",
+            "enter",
+        ]);
+        assert_eq!(
+            cli_prompt
+                .prompt_text_with_history("host", "hosts")
+                .unwrap(),
+            "g"
+        );
+
+        assert_eq!(
+            fs::read_to_string(dir.join("hosts-29ddeb14")).unwrap(),
+            "beta\nalpha\ng\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prompt_text_with_history_search() {
+        let dir = history_dir("text-search");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("hosts-29ddeb14"),
+            "db-1.example.com\nweb-1.example.com\ndb-2.example.com\n",
+        )
+        .unwrap();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_history_dir(&dir);
+
+        cli_prompt.push_key_inputs(&["\x12", "d", "b", "\x12", "enter", "end", "x", "enter"]);
+
+        assert_eq!(
+            cli_prompt
+                .prompt_text_with_history("host", "hosts")
+                .unwrap(),
+            "db-1.example.comx"
+        );
+
+        cli_prompt.push_key_inputs(&["a", "\x12", "z", "escape", "enter"]);
+
+        assert_eq!(
+            cli_prompt
+                .prompt_text_with_history("host", "hosts")
+                .unwrap(),
+            "a"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prompt_text_with_history_size() {
+        let dir = history_dir("text-size");
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_history_dir(&dir);
+        cli_prompt.set_history_size(0);
+
+        cli_prompt.push_key_inputs(&["a", "enter"]);
+        cli_prompt
+            .prompt_text_with_history("host", "hosts")
+            .unwrap();
+
+        assert!(!dir.join("hosts-29ddeb14").exists());
+    }
+
+    #[test]
+    fn test_prompt_password() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["p", "w", " ", "arrow up", "enter"]);
+
+        let result = cli_prompt.prompt_password("password?").unwrap();
+
+        assert_eq!(result, "pw ");
+        assert_eq!(
+            format!(
+                "{} password?\n{} {}\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                get_symbol("•", "*", supports_unicode::on(Stream::Stdout)).repeat(3),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    fn complete_environment(input: &str) -> Vec<String> {
+        ["development", "staging", "production", "preview"]
+            .iter()
+            .filter(|environment| environment.starts_with(input))
+            .map(|environment| environment.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_prompt_text_with_completer_single_candidate() {
+        let mut cli_prompt = CliPrompt::new();
+
+        cli_prompt.push_key_inputs(&["s", "tab", "enter"]);
+
+        let result = cli_prompt
+            .prompt_text_with_completer("environment", complete_environment)
+            .unwrap();
+
+        assert_eq!(result, "staging");
+    }
+
+    #[test]
+    fn test_prompt_text_with_completer_common_prefix() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+
+        cli_prompt.push_key_inputs(&["p", "tab", "enter"]);
+
+        let result = cli_prompt
+            .prompt_text_with_completer("environment", complete_environment)
+            .unwrap();
+
+        assert_eq!(result, "pr");
+
+        cli_prompt.push_key_inputs(&["p", "tab", "tab", "tab", "backtab", "tab", "enter"]);
+        cli_prompt.clear_term_output();
+
+        let result = cli_prompt
+            .prompt_text_with_completer("environment", complete_environment)
+            .unwrap();
+
+        assert_eq!(result, "preview");
+        assert_eq!(
+            format!(
+                "{} environment\n{} preview\n{}\n",
+                prefix_map.get("s_step_submit").unwrap(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_text_frame_with_candidates() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let completer = complete_environment;
+        let options = TextOptions {
+            completer: Some(&completer),
+            ..TextOptions::default()
+        };
+        let mut state = TextState {
+            buffer: LineBuffer::new("p"),
+            ..TextState::default()
+        };
+
+        assert!(complete(&mut state, &completer, &Key::Tab));
+
+        let bar = prefix_map.get("s_bar").unwrap();
+        assert_eq!(
+            cli_prompt.text_frame("environment", &state, &options, true)[1..],
+            [
+                format!("{} pr ", bar),
+                format!("{}   production", bar),
+                format!("{}   preview", bar),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(
+            common_prefix(&["한국어".to_string(), "한국".to_string()]),
+            "한국"
+        );
+        assert_eq!(common_prefix(&["a".to_string(), "b".to_string()]), "");
+    }
+}
@@ -0,0 +1,228 @@
+use crate::cli_prompt_error::CliPromptError;
+use crate::{CliPrompt, Key, MessageType};
+
+impl CliPrompt {
+    /// Prints the prompt message and let users choose either `active` or `inactive` label.
+    /// Users can change the selection by Arrow Left, Arrow Right and Tab key
+    /// and choose the selection by Enter key. `y` and `n` keys choose `active` and `inactive` right away.
+    ///
+    /// Returns true if user choose `active`.
+    ///
+    /// This function is same as calling [`CliPrompt::prompt_toggle_with_default`] with `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `active` - the label of the first choice, like `Deploy`
+    /// * `inactive` - the label of the second choice, like `Abort`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let deploy = cli_prompt
+    ///     .prompt_toggle("Deploy to production?", "Deploy", "Abort")
+    ///     .unwrap();
+    /// println!("{}", deploy);
+    /// ```
+    pub fn prompt_toggle(
+        &mut self,
+        message: &str,
+        active: &str,
+        inactive: &str,
+    ) -> Result<bool, CliPromptError> {
+        self.prompt_toggle_with_default(message, active, inactive, true)
+    }
+
+    /// Prints the prompt message and let users choose either `active` or `inactive` label,
+    /// with `default` selected at first. The keys are same as [`CliPrompt::prompt_toggle`].
+    ///
+    /// Returns true if user choose `active`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `active` - the label of the first choice, like `Deploy`
+    /// * `inactive` - the label of the second choice, like `Abort`
+    /// * `default` - `true` to select `active` at first
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let deploy = cli_prompt
+    ///     .prompt_toggle_with_default("Deploy to production?", "Deploy", "Abort", false)
+    ///     .unwrap();
+    /// println!("{}", deploy);
+    /// ```
+    pub fn prompt_toggle_with_default(
+        &mut self,
+        message: &str,
+        active: &str,
+        inactive: &str,
+        default: bool,
+    ) -> Result<bool, CliPromptError> {
+        let mut choice = default;
+
+        self.term.hide_cursor()?;
+        let frame = self.toggle_frame(message, active, inactive, choice, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match self.term.read_key()? {
+                Key::Enter => break,
+                Key::Char('y' | 'Y') => {
+                    choice = true;
+                    break;
+                }
+                Key::Char('n' | 'N') => {
+                    choice = false;
+                    break;
+                }
+                Key::ArrowLeft => choice = true,
+                Key::ArrowRight => choice = false,
+                Key::Tab | Key::BackTab => choice = !choice,
+                _ => continue,
+            }
+
+            let frame = self.toggle_frame(message, active, inactive, choice, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.toggle_frame(message, active, inactive, choice, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(choice)
+    }
+
+    /// Builds the frame of the toggle prompt. Once submitted, only the chosen label is drawn.
+    fn toggle_frame(
+        &self,
+        message: &str,
+        active: &str,
+        inactive: &str,
+        choice: bool,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            return vec![
+                question,
+                self.format_bar_line(if choice { active } else { inactive }),
+            ];
+        }
+
+        let radio = |is_active: bool| {
+            if is_active {
+                self.apply_style(&self.colors.active, &self.s_radio_active)
+                    .to_string()
+            } else {
+                self.s_radio_inactive.clone()
+            }
+        };
+
+        vec![
+            question,
+            self.format_bar_line(&format!(
+                "{} {} / {} {}",
+                radio(choice),
+                active,
+                radio(!choice),
+                inactive
+            )),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::build_prefix_map;
+    use crate::CliPrompt;
+    use console::style;
+
+    #[test]
+    fn test_toggle_frame() {
+        let prefix_map = build_prefix_map();
+        let cli_prompt = CliPrompt::new();
+
+        let frame = cli_prompt.toggle_frame("message", "Deploy", "Abort", false, true);
+
+        assert_eq!(
+            frame[1],
+            format!(
+                "{} {} Deploy / {} Abort",
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_radio_inactive").unwrap(),
+                style(prefix_map.get("s_radio_active").unwrap()).green()
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_toggle_collapsed_summary() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["tab", "enter"]);
+
+        let result = cli_prompt
+            .prompt_toggle("message", "Deploy", "Abort")
+            .unwrap();
+
+        assert!(!result);
+        assert_eq!(
+            format!(
+                "{} message\n{} Abort\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_toggle_with_default() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter"]);
+
+        assert!(!cli_prompt
+            .prompt_toggle_with_default("message", "Deploy", "Abort", false)
+            .unwrap());
+
+        cli_prompt.push_key_inputs(&["arrow left", "enter"]);
+
+        assert!(cli_prompt
+            .prompt_toggle_with_default("message", "Deploy", "Abort", false)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_prompt_toggle_hotkeys() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["n"]);
+
+        assert!(!cli_prompt
+            .prompt_toggle("message", "Deploy", "Abort")
+            .unwrap());
+
+        cli_prompt.push_key_inputs(&["arrow right", "Y"]);
+
+        assert!(cli_prompt
+            .prompt_toggle("message", "Deploy", "Abort")
+            .unwrap());
+
+        cli_prompt.push_key_inputs(&["n"]);
+
+        assert!(!cli_prompt.prompt_confirm("message").unwrap());
+        assert!(!cli_prompt
+            .prompt_confirm_with_default("message", false)
+            .unwrap());
+    }
+}
@@ -0,0 +1,579 @@
+use crate::cli_prompt_error::CliPromptError::{self, OptionsVecEmptyError};
+use crate::{CliPrompt, Key, MessageType, PromptSelectOption};
+
+/// A node of [`prompt_tree_select`](CliPrompt::prompt_tree_select) and
+/// [`prompt_tree_multi_select`](CliPrompt::prompt_tree_multi_select), holding an option and its child nodes.
+///
+/// # Example
+/// ```
+/// use cli_prompts_rs::TreeNode;
+///
+/// let catalog = vec![TreeNode::new("platform", "Platform").with_children(vec![
+///     TreeNode::new("auth", "Auth service"),
+///     TreeNode::new("billing", "Billing service"),
+/// ])];
+/// assert_eq!(catalog[0].children.len(), 2);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TreeNode {
+    pub option: PromptSelectOption,
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    pub fn new(value: &str, label: &str) -> Self {
+        Self {
+            option: PromptSelectOption::new(value, label),
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the node with `children` as its child nodes.
+    pub fn with_children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// A node flattened in pre-order, so that every node is addressed by its index.
+struct FlatNode<'a> {
+    option: &'a PromptSelectOption,
+    depth: usize,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    is_last: bool,
+}
+
+fn flatten<'a>(
+    nodes: &'a [TreeNode],
+    depth: usize,
+    parent: Option<usize>,
+    flat_nodes: &mut Vec<FlatNode<'a>>,
+) {
+    for (i, node) in nodes.iter().enumerate() {
+        let index = flat_nodes.len();
+        flat_nodes.push(FlatNode {
+            option: &node.option,
+            depth,
+            parent,
+            children: Vec::new(),
+            is_last: i == nodes.len() - 1,
+        });
+        if let Some(parent) = parent {
+            flat_nodes[parent].children.push(index);
+        }
+
+        flatten(&node.children, depth + 1, Some(index), flat_nodes);
+    }
+}
+
+/// Expanded and checked state of every flattened node, with the focused node.
+struct TreeState<'a> {
+    nodes: Vec<FlatNode<'a>>,
+    is_expanded: Vec<bool>,
+    is_checked: Vec<bool>,
+    choice: usize,
+}
+
+impl<'a> TreeState<'a> {
+    fn new(nodes: &'a [TreeNode]) -> Self {
+        let mut flat_nodes = Vec::new();
+        flatten(nodes, 0, None, &mut flat_nodes);
+        let len = flat_nodes.len();
+
+        Self {
+            nodes: flat_nodes,
+            is_expanded: vec![false; len],
+            is_checked: vec![false; len],
+            choice: 0,
+        }
+    }
+
+    /// Returns the indexes of the nodes whose ancestors are all expanded.
+    fn visible(&self) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&i| {
+                let mut parent = self.nodes[i].parent;
+                while let Some(p) = parent {
+                    if !self.is_expanded[p] {
+                        return false;
+                    }
+                    parent = self.nodes[p].parent;
+                }
+                true
+            })
+            .collect()
+    }
+
+    fn move_focus(&mut self, is_down: bool) {
+        let visible = self.visible();
+        let position = visible.iter().position(|&i| i == self.choice).unwrap_or(0);
+        let len = visible.len();
+
+        self.choice = if is_down {
+            visible[(position + 1) % len]
+        } else {
+            visible[(position + len - 1) % len]
+        };
+    }
+
+    /// Expands the focused node, or moves into its first child if it is already expanded.
+    fn expand(&mut self) -> bool {
+        let children = &self.nodes[self.choice].children;
+        if children.is_empty() {
+            return false;
+        }
+
+        if self.is_expanded[self.choice] {
+            self.choice = children[0];
+        } else {
+            self.is_expanded[self.choice] = true;
+        }
+        true
+    }
+
+    /// Collapses the focused node, or moves to its parent if it is already collapsed.
+    fn collapse(&mut self) -> bool {
+        if self.is_expanded[self.choice] {
+            self.is_expanded[self.choice] = false;
+            return true;
+        }
+
+        match self.nodes[self.choice].parent {
+            Some(parent) => {
+                self.choice = parent;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Toggles the focused node with all of its descendants.
+    /// Then every ancestor is checked only if all of its children are checked.
+    fn toggle(&mut self) {
+        let is_checked = !self.is_checked[self.choice];
+
+        let mut stack = vec![self.choice];
+        while let Some(i) = stack.pop() {
+            self.is_checked[i] = is_checked;
+            stack.extend(&self.nodes[i].children);
+        }
+
+        let mut parent = self.nodes[self.choice].parent;
+        while let Some(p) = parent {
+            self.is_checked[p] = self.nodes[p].children.iter().all(|&c| self.is_checked[c]);
+            parent = self.nodes[p].parent;
+        }
+    }
+
+    fn checked_options(&self) -> Vec<PromptSelectOption> {
+        self.nodes
+            .iter()
+            .zip(&self.is_checked)
+            .filter(|(_, &is_checked)| is_checked)
+            .map(|(node, _)| node.option.clone())
+            .collect()
+    }
+}
+
+impl CliPrompt {
+    /// Prints the prompt message with a tree of the provided nodes and let users choose one of them.
+    /// Users can change the focus by Arrow Up and Arrow Down key,
+    /// expand the focused node by Arrow Right key and collapse it by Arrow Left key,
+    /// and choose the focused node by Enter key. Every node can be chosen, not only the leaves.
+    ///
+    /// Returns the option of the chosen node as instance of [`PromptSelectOption`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `nodes` - the top level nodes of the tree
+    ///
+    /// # Errors
+    ///
+    /// If `nodes` is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, TreeNode};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let catalog = vec![
+    ///     TreeNode::new("platform", "Platform").with_children(vec![
+    ///         TreeNode::new("auth", "Auth service"),
+    ///         TreeNode::new("billing", "Billing service"),
+    ///     ]),
+    ///     TreeNode::new("data", "Data").with_children(vec![TreeNode::new("etl", "ETL service")]),
+    /// ];
+    /// let service = cli_prompt.prompt_tree_select("Pick a service", catalog).unwrap();
+    /// println!("{}", service);
+    /// ```
+    pub fn prompt_tree_select(
+        &mut self,
+        message: &str,
+        nodes: Vec<TreeNode>,
+    ) -> Result<PromptSelectOption, CliPromptError> {
+        let mut options = self.read_tree(message, &nodes, false)?;
+        Ok(options.remove(0))
+    }
+
+    /// Prints the prompt message with a tree of the provided nodes and let users check multiple of them.
+    /// Users can check the focused node by Space key, which checks all of its descendants too.
+    /// A parent node is checked only while all of its children are checked.
+    /// The other keys are same as [`CliPrompt::prompt_tree_select`], and Enter key submits the checked nodes.
+    ///
+    /// Returns the options of the checked nodes in the tree order as `Vector` of [`PromptSelectOption`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the prompt message
+    /// * `nodes` - the top level nodes of the tree
+    ///
+    /// # Errors
+    ///
+    /// If `nodes` is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// ```
+    /// use cli_prompts_rs::CliPrompt;
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let result = cli_prompt.prompt_tree_multi_select("Pick services", vec![]);
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, TreeNode};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let catalog = vec![TreeNode::new("platform", "Platform").with_children(vec![
+    ///     TreeNode::new("auth", "Auth service"),
+    ///     TreeNode::new("billing", "Billing service"),
+    /// ])];
+    /// let services = cli_prompt.prompt_tree_multi_select("Pick services", catalog).unwrap();
+    /// println!("{:?}", services);
+    /// ```
+    pub fn prompt_tree_multi_select(
+        &mut self,
+        message: &str,
+        nodes: Vec<TreeNode>,
+    ) -> Result<Vec<PromptSelectOption>, CliPromptError> {
+        self.read_tree(message, &nodes, true)
+    }
+
+    /// Runs the tree prompt. In single-select mode, the result holds only the focused option.
+    fn read_tree(
+        &mut self,
+        message: &str,
+        nodes: &[TreeNode],
+        is_multi: bool,
+    ) -> Result<Vec<PromptSelectOption>, CliPromptError> {
+        if nodes.is_empty() {
+            return Err(OptionsVecEmptyError {
+                message: "nodes is empty".to_string(),
+            });
+        }
+
+        let mut state = TreeState::new(nodes);
+
+        self.term.hide_cursor()?;
+        let frame = self.tree_frame(message, &state, is_multi, true);
+        let mut height = self.draw_frame(&frame, 0)?;
+
+        loop {
+            match self.term.read_key()? {
+                Key::Enter => break,
+                Key::ArrowUp => state.move_focus(false),
+                Key::ArrowDown => state.move_focus(true),
+                Key::ArrowRight => {
+                    if !state.expand() {
+                        continue;
+                    }
+                }
+                Key::ArrowLeft => {
+                    if !state.collapse() {
+                        continue;
+                    }
+                }
+                Key::Char(' ') if is_multi => state.toggle(),
+                _ => continue,
+            }
+
+            let frame = self.tree_frame(message, &state, is_multi, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.tree_frame(message, &state, is_multi, false);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        if is_multi {
+            Ok(state.checked_options())
+        } else {
+            Ok(vec![state.nodes[state.choice].option.clone()])
+        }
+    }
+
+    /// Builds the frame of the tree prompt. Once submitted, only the chosen labels are drawn.
+    fn tree_frame(
+        &self,
+        message: &str,
+        state: &TreeState,
+        is_multi: bool,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            let summary = if is_multi {
+                state
+                    .checked_options()
+                    .iter()
+                    .map(|option| option.label.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            } else {
+                state.nodes[state.choice].option.label.clone()
+            };
+            return vec![question, self.format_bar_line(&summary)];
+        }
+
+        let mut lines = vec![question];
+        for i in state.visible() {
+            let node = &state.nodes[i];
+            let marker = if node.children.is_empty() {
+                " ".to_string()
+            } else if state.is_expanded[i] {
+                self.s_tree_expanded.clone()
+            } else {
+                self.s_tree_collapsed.clone()
+            };
+            let checkbox = if !is_multi {
+                String::new()
+            } else if state.is_checked[i] {
+                format!(
+                    "{} ",
+                    self.apply_style(&self.colors.selected, &self.s_checkbox_active)
+                )
+            } else {
+                format!("{} ", self.s_checkbox_inactive)
+            };
+
+            lines.push(self.format_radio_option(
+                &format!(
+                    "{}{} {}{}",
+                    self.tree_guide(state, i),
+                    marker,
+                    checkbox,
+                    node.option.label
+                ),
+                i == state.choice,
+            ));
+        }
+        lines.push(self.format_hint(if is_multi {
+            "right/left to expand/collapse, space to check, enter to submit"
+        } else {
+            "right/left to expand/collapse, enter to select"
+        }));
+
+        lines
+    }
+
+    /// Draws the indentation guide of the node like `│  ├─ `, with the box-drawing symbols.
+    fn tree_guide(&self, state: &TreeState, index: usize) -> String {
+        let node = &state.nodes[index];
+        if node.depth == 0 {
+            return String::new();
+        }
+
+        let mut guide = if node.is_last {
+            format!("{}{} ", self.s_bar_end, self.s_bar_h)
+        } else {
+            format!("{}{} ", self.s_connect_left, self.s_bar_h)
+        };
+
+        let mut parent = node.parent;
+        while let Some(p) = parent {
+            if state.nodes[p].depth == 0 {
+                break;
+            }
+            if state.nodes[p].is_last {
+                guide.insert_str(0, "   ");
+            } else {
+                guide.insert_str(0, &format!("{}  ", self.s_bar));
+            }
+            parent = state.nodes[p].parent;
+        }
+
+        guide
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_nodes() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new("org", "Org").with_children(vec![
+                TreeNode::new("web", "Web team").with_children(vec![
+                    TreeNode::new("site", "Site"),
+                    TreeNode::new("cdn", "CDN"),
+                ]),
+                TreeNode::new("ops", "Ops team").with_children(vec![TreeNode::new("ci", "CI")]),
+            ]),
+            TreeNode::new("misc", "Misc"),
+        ]
+    }
+
+    fn values(options: &[PromptSelectOption]) -> Vec<&str> {
+        options.iter().map(|option| option.value.as_str()).collect()
+    }
+
+    #[test]
+    fn test_tree_state_visible() {
+        let nodes = build_nodes();
+        let mut state = TreeState::new(&nodes);
+
+        assert_eq!(state.visible(), vec![0, 6]);
+
+        state.expand();
+        state.expand();
+        state.expand();
+
+        assert_eq!(state.choice, 1);
+        assert_eq!(state.visible(), vec![0, 1, 2, 3, 4, 6]);
+
+        state.collapse();
+        state.collapse();
+
+        assert_eq!(state.choice, 0);
+        assert_eq!(state.visible(), vec![0, 1, 4, 6]);
+    }
+
+    #[test]
+    fn test_tree_state_toggle() {
+        let nodes = build_nodes();
+        let mut state = TreeState::new(&nodes);
+
+        state.toggle();
+        assert_eq!(
+            values(&state.checked_options()),
+            vec!["org", "web", "site", "cdn", "ops", "ci"]
+        );
+
+        // unchecking a child unchecks its ancestors
+        state.choice = 3;
+        state.toggle();
+        assert_eq!(values(&state.checked_options()), vec!["site", "ops", "ci"]);
+
+        // checking it again completes its parents
+        state.toggle();
+        assert_eq!(state.checked_options().len(), 6);
+    }
+
+    #[test]
+    fn test_tree_frame() {
+        let prefix_map = build_prefix_map();
+        let nodes = build_nodes();
+        let mut state = TreeState::new(&nodes);
+        state.is_expanded = vec![true; state.nodes.len()];
+        state.choice = 2;
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+
+        let frame = cli_prompt.tree_frame("message", &state, false, true);
+        let bar = prefix_map.get("s_bar").unwrap();
+        let inactive = prefix_map.get("s_radio_inactive").unwrap();
+        let bar_h = prefix_map.get("s_bar_h").unwrap();
+        let connect = prefix_map.get("s_connect_left").unwrap();
+        let end = prefix_map.get("s_bar_end").unwrap();
+
+        assert_eq!(
+            frame[1],
+            format!(
+                "{} {} {} Org",
+                bar,
+                prefix_map.get("s_radio_inactive").unwrap(),
+                prefix_map.get("s_tree_expanded").unwrap()
+            )
+        );
+        assert_eq!(
+            frame[3],
+            format!(
+                "{} {} {}  {}{}   Site",
+                bar,
+                prefix_map.get("s_radio_active").unwrap(),
+                bar,
+                connect,
+                bar_h
+            )
+        );
+        assert_eq!(
+            frame[6],
+            format!("{} {}    {}{}   CI", bar, inactive, end, bar_h)
+        );
+        assert_eq!(frame[7], format!("{} {}   Misc", bar, inactive));
+    }
+
+    #[test]
+    fn test_prompt_tree_select() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["arrow right", "arrow right", "arrow down", "enter"]);
+
+        let result = cli_prompt
+            .prompt_tree_select("message", build_nodes())
+            .unwrap();
+
+        assert_eq!(result.value, "ops");
+        assert_eq!(
+            format!(
+                "{} message\n{} Ops team\n{}\n",
+                style(prefix_map.get("s_step_submit").unwrap()).magenta(),
+                prefix_map.get("s_bar").unwrap(),
+                prefix_map.get("s_bar").unwrap()
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_tree_multi_select() {
+        let mut cli_prompt = CliPrompt::new();
+        // check "Web team", then move back to "Org" and check "Misc"
+        cli_prompt.push_key_inputs(&[
+            "arrow right",
+            "arrow right",
+            " ",
+            "arrow left",
+            "arrow left",
+            "arrow up",
+            " ",
+            "enter",
+        ]);
+
+        let result = cli_prompt
+            .prompt_tree_multi_select("message", build_nodes())
+            .unwrap();
+
+        assert_eq!(values(&result), vec!["web", "site", "cdn", "misc"]);
+    }
+
+    #[test]
+    fn test_prompt_tree_select_empty() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_tree_select("message", vec![]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+    }
+}
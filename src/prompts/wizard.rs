@@ -0,0 +1,743 @@
+use crate::cli_prompt_error::CliPromptError::{self, InvalidFormError, OptionsVecEmptyError};
+use crate::prompts::form::{check_unique_names, FieldState};
+use crate::{CliPrompt, FormField, FormValue, Key, MessageType, PromptSelectOption};
+use std::collections::HashMap;
+
+/// A step of [`prompt_wizard`](CliPrompt::prompt_wizard) and [`prompt_group`](CliPrompt::prompt_group), asking for a single value.
+///
+/// # Example
+/// ```
+/// use cli_prompts_rs::{FormField, PromptSelectOption, WizardStep};
+///
+/// let steps = vec![
+///     WizardStep::field(FormField::text("name", "Project name")),
+///     WizardStep::select(
+///         "template",
+///         "Template",
+///         vec![
+///             PromptSelectOption::new("web", "Web app"),
+///             PromptSelectOption::new("cli", "Command line tool"),
+///         ],
+///     ),
+/// ];
+/// assert_eq!(steps[1].name(), "template");
+/// ```
+pub struct WizardStep {
+    kind: WizardStepKind,
+    skip_if: Option<SkipIf>,
+    next: Option<NextStep>,
+}
+
+/// Decides from the answers so far whether to skip the step.
+type SkipIf = Box<dyn Fn(&WizardAnswers) -> bool>;
+
+/// Chooses from the answers so far the name of the step to read next.
+type NextStep = Box<dyn Fn(&WizardAnswers) -> Option<String>>;
+
+enum WizardStepKind {
+    /// Edited like the field of [`prompt_form`](CliPrompt::prompt_form), with the label as the prompt message
+    Field(FormField),
+    /// Chosen like [`prompt_select`](CliPrompt::prompt_select), returning the value of the option
+    Select {
+        name: String,
+        message: String,
+        options: Vec<PromptSelectOption>,
+    },
+}
+
+impl WizardStep {
+    /// Creates the step asking for the value of `field`, with its label as the prompt message.
+    pub fn field(field: FormField) -> Self {
+        Self::new(WizardStepKind::Field(field))
+    }
+
+    /// Creates the step choosing one of `options`. Its answer is [`FormValue::Text`] of the chosen value.
+    pub fn select(name: &str, message: &str, options: Vec<PromptSelectOption>) -> Self {
+        Self::new(WizardStepKind::Select {
+            name: name.to_string(),
+            message: message.to_string(),
+            options,
+        })
+    }
+
+    fn new(kind: WizardStepKind) -> Self {
+        Self {
+            kind,
+            skip_if: None,
+            next: None,
+        }
+    }
+
+    /// Returns the step skipped if `skip_if` returns true for the answers of the steps before it.
+    /// A skipped step has no answer.
+    pub fn with_skip_if<F>(mut self, skip_if: F) -> Self
+    where
+        F: Fn(&WizardAnswers) -> bool + 'static,
+    {
+        self.skip_if = Some(Box::new(skip_if));
+        self
+    }
+
+    /// Returns the step followed by the step named by `next`, which takes the answers including this step.
+    /// If `next` returns `None`, the step is followed by the one after it.
+    pub fn with_next<F>(mut self, next: F) -> Self
+    where
+        F: Fn(&WizardAnswers) -> Option<String> + 'static,
+    {
+        self.next = Some(Box::new(next));
+        self
+    }
+
+    /// Key of the answer in the result of [`prompt_wizard`](CliPrompt::prompt_wizard)
+    pub fn name(&self) -> &str {
+        match &self.kind {
+            WizardStepKind::Field(field) => &field.name,
+            WizardStepKind::Select { name, .. } => name,
+        }
+    }
+
+    fn is_skipped(&self, answers: &WizardAnswers) -> bool {
+        self.skip_if
+            .as_ref()
+            .is_some_and(|skip_if| skip_if(answers))
+    }
+}
+
+/// Answers of the steps users went through, keyed by [`WizardStep::name`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct WizardAnswers {
+    values: HashMap<String, FormValue>,
+}
+
+impl WizardAnswers {
+    pub fn get(&self, name: &str) -> Option<&FormValue> {
+        self.values.get(name)
+    }
+
+    /// Returns the answer if it is [`FormValue::Text`], like the value of the chosen option of select steps.
+    pub fn text(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(FormValue::as_str)
+    }
+
+    /// Returns the answer if it is [`FormValue::Number`].
+    pub fn number(&self, name: &str) -> Option<i64> {
+        self.get(name).and_then(FormValue::as_number)
+    }
+
+    /// Returns the answer if it is [`FormValue::Bool`].
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        self.get(name).and_then(FormValue::as_bool)
+    }
+
+    pub fn into_map(self) -> HashMap<String, FormValue> {
+        self.values
+    }
+}
+
+/// How the wizard ended, with the answers so far.
+/// On cancel, the answers are the latest ones users gave to the steps, see [`draft_answers`].
+pub(super) enum WizardEnd {
+    Finished(WizardAnswers),
+    Cancelled(WizardAnswers),
+}
+
+/// How users left a step of the wizard.
+enum StepOutcome {
+    /// Answered the step, whose collapsed frame has `height` lines including the empty line
+    Next { value: FormValue, height: usize },
+    /// Pressed Escape key on the step, whose frame of `height` lines is left drawn
+    Back { height: usize },
+    /// Pressed Ctrl+C on the step, whose frame of `height` lines is left drawn
+    Cancel { height: usize },
+}
+
+/// Position of the step in the wizard, and the height of the frames to clear before drawing it.
+struct StepContext {
+    position: usize,
+    /// Number of the steps, unknown if some steps may be skipped or choose the next step
+    len: Option<usize>,
+    cleared_height: usize,
+}
+
+/// Finds the step to read after `previous`, or the first step if `previous` is `None`,
+/// following [`WizardStep::with_next`] and [`WizardStep::with_skip_if`].
+///
+/// Returns `None` if no step is left.
+fn next_step(
+    steps: &[WizardStep],
+    previous: Option<usize>,
+    answers: &WizardAnswers,
+) -> Result<Option<usize>, CliPromptError> {
+    let mut index = match previous {
+        None => 0,
+        Some(previous) => match steps[previous].next.as_ref().and_then(|next| next(answers)) {
+            Some(name) => steps
+                .iter()
+                .position(|step| step.name() == name)
+                .ok_or_else(|| InvalidFormError {
+                    message: format!("step \"{}\" doesn't exist", name),
+                })?,
+            None => previous + 1,
+        },
+    };
+
+    while index < steps.len() && steps[index].is_skipped(answers) {
+        index += 1;
+    }
+
+    Ok((index < steps.len()).then_some(index))
+}
+
+/// Collects the drafts of the steps from the first one, following the branches chosen by the drafts,
+/// until a step without a draft.
+fn draft_answers(steps: &[WizardStep], drafts: &HashMap<String, FormValue>) -> WizardAnswers {
+    let mut answers = WizardAnswers::default();
+    let mut current = next_step(steps, None, &answers).ok().flatten();
+
+    while let Some(index) = current {
+        let name = steps[index].name();
+        // a step choosing an earlier step as the next one would loop forever
+        if answers.values.contains_key(name) {
+            break;
+        }
+        let Some(value) = drafts.get(name) else {
+            break;
+        };
+        answers.values.insert(name.to_string(), value.clone());
+        current = next_step(steps, Some(index), &answers).ok().flatten();
+    }
+
+    answers
+}
+
+impl CliPrompt {
+    /// Prints the provided steps one by one, and let users answer them in order.
+    /// Escape key goes back to the previous step, re-opening it with its previous answer,
+    /// so users can fix an answer without starting over. The answers of the later steps
+    /// are also kept, and shown again when users get back to them.
+    /// Escape key on the first step or Ctrl+C on any step cancels the wizard.
+    /// Steps with [`WizardStep::with_skip_if`] or [`WizardStep::with_next`] are followed like [`CliPrompt::prompt_group`].
+    ///
+    /// Field steps are edited like [`CliPrompt::prompt_form`], and select steps like [`CliPrompt::prompt_select`].
+    ///
+    /// Returns the answers keyed by [`WizardStep::name`] as `HashMap` of [`FormValue`] wrapped in `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - the list of steps
+    ///
+    /// # Errors
+    ///
+    /// If `steps` or the options of a select step is empty, [`OptionsVecEmptyError`](OptionsVecEmptyError) will be returned.
+    ///
+    /// If two steps have the same name, [`InvalidFormError`](CliPromptError::InvalidFormError) will be returned.
+    ///
+    /// If users press Escape key on the first step or Ctrl+C, [`Cancelled`](CliPromptError::Cancelled) will be returned.
+    /// ```
+    /// use cli_prompts_rs::{CliPrompt, FormField, WizardStep};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let steps = vec![
+    ///     WizardStep::field(FormField::text("name", "Project name")),
+    ///     WizardStep::select("template", "Template", vec![]),
+    /// ];
+    ///
+    /// let result = cli_prompt.prompt_wizard(steps);
+    ///
+    /// assert!(result.is_err());
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.to_string(), "options of \"template\" is empty");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_prompts_rs::{CliPrompt, FormField, PromptSelectOption, WizardStep};
+    ///
+    /// let mut cli_prompt = CliPrompt::new();
+    /// let steps = vec![
+    ///     WizardStep::field(FormField::text("name", "Project name").with_default("my-app")),
+    ///     WizardStep::select(
+    ///         "template",
+    ///         "Template",
+    ///         vec![
+    ///             PromptSelectOption::new("web", "Web app"),
+    ///             PromptSelectOption::new("cli", "Command line tool"),
+    ///         ],
+    ///     ),
+    ///     WizardStep::field(FormField::number("port", "Port").with_default(8080)),
+    ///     WizardStep::field(FormField::toggle("git", "Initialize git?", "Yes", "No")),
+    /// ];
+    ///
+    /// let answers = cli_prompt.prompt_wizard(steps).unwrap();
+    /// println!("{} ({})", answers["name"], answers["template"]);
+    /// ```
+    pub fn prompt_wizard(
+        &mut self,
+        steps: Vec<WizardStep>,
+    ) -> Result<HashMap<String, FormValue>, CliPromptError> {
+        match self.run_wizard(&steps)? {
+            WizardEnd::Finished(answers) => Ok(answers.into_map()),
+            WizardEnd::Cancelled(_) => Err(CliPromptError::Cancelled),
+        }
+    }
+
+    /// Reads the steps until users answer the last one or cancel the wizard.
+    pub(super) fn run_wizard(&mut self, steps: &[WizardStep]) -> Result<WizardEnd, CliPromptError> {
+        if steps.is_empty() {
+            return Err(OptionsVecEmptyError {
+                message: "steps is empty".to_string(),
+            });
+        }
+
+        for step in steps {
+            if let WizardStepKind::Select { name, options, .. } = &step.kind {
+                if options.is_empty() {
+                    return Err(OptionsVecEmptyError {
+                        message: format!("options of \"{}\" is empty", name),
+                    });
+                }
+            }
+        }
+
+        let names: Vec<&str> = steps.iter().map(WizardStep::name).collect();
+        check_unique_names(&names)?;
+
+        let has_branches = steps
+            .iter()
+            .any(|step| step.skip_if.is_some() || step.next.is_some());
+        let mut answers = WizardAnswers::default();
+        // Answers shown again on re-opened steps, including the steps users went back from
+        let mut drafts: HashMap<String, FormValue> = HashMap::new();
+        // Answered steps with the heights of their collapsed frames, to clear them on going back
+        let mut answered: Vec<(usize, usize)> = Vec::new();
+        let mut current = next_step(steps, None, &answers)?;
+        let mut cleared_height = 0;
+
+        while let Some(index) = current {
+            let step = &steps[index];
+            let context = StepContext {
+                position: answered.len(),
+                len: (!has_branches).then_some(steps.len()),
+                cleared_height,
+            };
+
+            match self.read_wizard_step(step, drafts.get(step.name()), context)? {
+                StepOutcome::Next { value, height } => {
+                    drafts.insert(step.name().to_string(), value.clone());
+                    answers.values.insert(step.name().to_string(), value);
+                    answered.push((index, height));
+                    cleared_height = 0;
+                    current = next_step(steps, Some(index), &answers)?;
+                }
+                StepOutcome::Back { height } => match answered.pop() {
+                    Some((previous, previous_height)) => {
+                        answers.values.remove(steps[previous].name());
+                        current = Some(previous);
+                        cleared_height = height + previous_height;
+                    }
+                    None => return self.cancel_wizard(steps, step, &drafts, height),
+                },
+                StepOutcome::Cancel { height } => {
+                    return self.cancel_wizard(steps, step, &drafts, height)
+                }
+            }
+        }
+
+        Ok(WizardEnd::Finished(answers))
+    }
+
+    /// Draws the step on which users cancelled the wizard, and ends it with their latest answers.
+    fn cancel_wizard(
+        &mut self,
+        steps: &[WizardStep],
+        step: &WizardStep,
+        drafts: &HashMap<String, FormValue>,
+        height: usize,
+    ) -> Result<WizardEnd, CliPromptError> {
+        let frame = self.wizard_cancelled_frame(step);
+        self.draw_frame(&frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(WizardEnd::Cancelled(draft_answers(steps, drafts)))
+    }
+
+    /// Reads the answer of the step, showing `answer` at first if the step was answered before,
+    /// otherwise the default value of the field.
+    fn read_wizard_step(
+        &mut self,
+        step: &WizardStep,
+        answer: Option<&FormValue>,
+        context: StepContext,
+    ) -> Result<StepOutcome, CliPromptError> {
+        match &step.kind {
+            WizardStepKind::Field(field) => self.read_wizard_field(field, answer, context),
+            WizardStepKind::Select {
+                message, options, ..
+            } => self.read_wizard_select(message, options, answer, context),
+        }
+    }
+
+    fn read_wizard_field(
+        &mut self,
+        field: &FormField,
+        answer: Option<&FormValue>,
+        context: StepContext,
+    ) -> Result<StepOutcome, CliPromptError> {
+        let mut state = FieldState::with_value(field, answer.or(field.default.as_ref()));
+
+        self.term.hide_cursor()?;
+        let frame = self.wizard_field_frame(field, &state, &context, true);
+        let mut height = self.draw_frame(&frame, context.cleared_height)?;
+
+        let value = loop {
+            match self.term.read_key_raw()? {
+                Key::Escape => return Ok(StepOutcome::Back { height }),
+                Key::CtrlC => return Ok(StepOutcome::Cancel { height }),
+                Key::Enter => match state.value(field) {
+                    Ok(value) => break value,
+                    Err(error) => state.error = Some(error),
+                },
+                key => {
+                    if !state.edit(field, &key) {
+                        continue;
+                    }
+                }
+            }
+
+            let frame = self.wizard_field_frame(field, &state, &context, true);
+            height = self.draw_frame(&frame, height)?;
+        };
+
+        let frame = self.wizard_field_frame(field, &state, &context, false);
+        self.finish_wizard_step(&frame, height, value)
+    }
+
+    fn read_wizard_select(
+        &mut self,
+        message: &str,
+        options: &[PromptSelectOption],
+        answer: Option<&FormValue>,
+        context: StepContext,
+    ) -> Result<StepOutcome, CliPromptError> {
+        let mut choice = answer
+            .and_then(FormValue::as_str)
+            .and_then(|value| options.iter().position(|option| option.value == value))
+            .unwrap_or(0);
+
+        self.term.hide_cursor()?;
+        let frame = self.wizard_select_frame(message, options, choice, &context, true);
+        let mut height = self.draw_frame(&frame, context.cleared_height)?;
+
+        loop {
+            match self.term.read_key_raw()? {
+                Key::Escape => return Ok(StepOutcome::Back { height }),
+                Key::CtrlC => return Ok(StepOutcome::Cancel { height }),
+                Key::Enter => break,
+                Key::ArrowUp => choice = (choice + options.len() - 1) % options.len(),
+                Key::ArrowDown => choice = (choice + 1) % options.len(),
+                _ => continue,
+            }
+
+            let frame = self.wizard_select_frame(message, options, choice, &context, true);
+            height = self.draw_frame(&frame, height)?;
+        }
+
+        let frame = self.wizard_select_frame(message, options, choice, &context, false);
+        let value = FormValue::Text(options[choice].value.clone());
+        self.finish_wizard_step(&frame, height, value)
+    }
+
+    /// Draws the collapsed frame of the answered step.
+    fn finish_wizard_step(
+        &mut self,
+        frame: &[String],
+        height: usize,
+        value: FormValue,
+    ) -> Result<StepOutcome, CliPromptError> {
+        let height = self.draw_frame(frame, height)?;
+        self.term.show_cursor()?;
+        self.print_empty_line()?;
+
+        Ok(StepOutcome::Next {
+            value,
+            height: height + 1,
+        })
+    }
+
+    /// Builds the frame of the field step. Once answered, only the value is drawn.
+    fn wizard_field_frame(
+        &self,
+        field: &FormField,
+        state: &FieldState,
+        context: &StepContext,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let mut frame = vec![
+            self.format_prefix(field.label.clone(), MessageType::Question),
+            self.format_bar_line(&self.format_field_value(field, state, is_editing, is_editing)),
+        ];
+
+        if !is_editing {
+            return frame;
+        }
+
+        if let Some(error) = &state.error {
+            frame.push(
+                self.format_bar_line(&self.apply_style(&self.colors.error, error).to_string()),
+            );
+        }
+        frame.push(self.format_wizard_hint(context));
+
+        frame
+    }
+
+    /// Builds the frame of the select step. Once answered, only the label of the chosen option is drawn.
+    fn wizard_select_frame(
+        &self,
+        message: &str,
+        options: &[PromptSelectOption],
+        choice: usize,
+        context: &StepContext,
+        is_editing: bool,
+    ) -> Vec<String> {
+        let question = self.format_prefix(message.to_string(), MessageType::Question);
+
+        if !is_editing {
+            return vec![question, self.format_bar_line(&options[choice].label)];
+        }
+
+        let mut frame = vec![question];
+        for (i, option) in options.iter().enumerate() {
+            frame.push(self.format_radio_option(&option.label, i == choice));
+        }
+        frame.push(self.format_wizard_hint(context));
+
+        frame
+    }
+
+    /// Formats the hint with the position of the step, like `(2/4) enter to continue, esc to go back, ctrl+c to cancel`.
+    /// The position is omitted if the number of the steps is unknown.
+    fn format_wizard_hint(&self, context: &StepContext) -> String {
+        let keys = if context.position == 0 {
+            "enter to continue, esc or ctrl+c to cancel"
+        } else {
+            "enter to continue, esc to go back, ctrl+c to cancel"
+        };
+
+        match context.len {
+            Some(len) => self.format_hint(&format!("({}/{}) {}", context.position + 1, len, keys)),
+            None => self.format_hint(keys),
+        }
+    }
+
+    /// Builds the frame of the step on which users cancelled the wizard.
+    fn wizard_cancelled_frame(&self, step: &WizardStep) -> Vec<String> {
+        let message = match &step.kind {
+            WizardStepKind::Field(field) => &field.label,
+            WizardStepKind::Select { message, .. } => message,
+        };
+
+        vec![
+            self.format_prefix(message.to_string(), MessageType::Question),
+            self.format_bar_line(
+                &self
+                    .apply_style(&self.colors.cancel, "Cancelled")
+                    .to_string(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+    use crate::tests::build_prefix_map;
+    use console::style;
+
+    fn build_steps() -> Vec<WizardStep> {
+        vec![
+            WizardStep::field(FormField::text("name", "Name").with_default("app")),
+            WizardStep::select(
+                "template",
+                "Template",
+                vec![
+                    PromptSelectOption::new("web", "Web app"),
+                    PromptSelectOption::new("cli", "Command line tool"),
+                ],
+            ),
+            WizardStep::field(FormField::number("port", "Port")),
+        ]
+    }
+
+    #[test]
+    fn test_prompt_wizard() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter", "arrow down", "enter", "8", "enter"]);
+
+        let answers = cli_prompt.prompt_wizard(build_steps()).unwrap();
+
+        assert_eq!(answers["name"].as_str(), Some("app"));
+        assert_eq!(answers["template"].as_str(), Some("cli"));
+        assert_eq!(answers["port"].as_number(), Some(8));
+
+        let step = style(prefix_map.get("s_step_submit").unwrap()).magenta();
+        let bar = prefix_map.get("s_bar").unwrap();
+        assert_eq!(
+            format!(
+                "{step} Name\n{bar} app\n{bar}\n{step} Template\n{bar} Command line tool\n{bar}\n{step} Port\n{bar} 8\n{bar}\n",
+                step = step,
+                bar = bar
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_wizard_back() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        // the re-opened steps show the previous answers
+        cli_prompt.push_key_inputs(&[
+            "enter",
+            "arrow down",
+            "enter",
+            "x",
+            "escape",
+            "escape",
+            "end",
+            "2",
+            "enter",
+            "enter",
+            "backspace",
+            "9",
+            "enter",
+        ]);
+
+        let answers = cli_prompt.prompt_wizard(build_steps()).unwrap();
+
+        assert_eq!(answers["name"].as_str(), Some("app2"));
+        assert_eq!(answers["template"].as_str(), Some("cli"));
+        assert_eq!(answers["port"].as_number(), Some(9));
+
+        let step = style(prefix_map.get("s_step_submit").unwrap()).magenta();
+        let bar = prefix_map.get("s_bar").unwrap();
+        assert_eq!(
+            format!(
+                "{step} Name\n{bar} app2\n{bar}\n{step} Template\n{bar} Command line tool\n{bar}\n{step} Port\n{bar} 9\n{bar}\n",
+                step = step,
+                bar = bar
+            ),
+            cli_prompt.term.get_output_string()
+        );
+    }
+
+    #[test]
+    fn test_prompt_wizard_validation() {
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter", "enter", "a", "enter", "backspace", "1", "enter"]);
+
+        let answers = cli_prompt.prompt_wizard(build_steps()).unwrap();
+
+        assert_eq!(answers["port"].as_number(), Some(1));
+    }
+
+    #[test]
+    fn test_prompt_wizard_cancelled() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter", "escape", "escape"]);
+
+        let result = cli_prompt.prompt_wizard(build_steps());
+
+        assert!(matches!(result, Err(CliPromptError::Cancelled)));
+
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.push_key_inputs(&["enter", "ctrl c"]);
+
+        let result = cli_prompt.prompt_wizard(build_steps());
+
+        assert!(matches!(result, Err(CliPromptError::Cancelled)));
+        assert!(cli_prompt.term.get_output_string().ends_with(&format!(
+            "Cancelled\n{}\n",
+            prefix_map.get("s_bar").unwrap()
+        )));
+    }
+
+    #[test]
+    fn test_draft_answers() {
+        let steps = vec![
+            WizardStep::field(FormField::text("a", "A")).with_next(|_| Some("c".to_string())),
+            WizardStep::field(FormField::text("b", "B")),
+            WizardStep::field(FormField::text("c", "C")).with_next(|_| Some("a".to_string())),
+            WizardStep::field(FormField::text("d", "D")),
+        ];
+        let drafts: HashMap<String, FormValue> = ["a", "b", "c", "d"]
+            .iter()
+            .map(|name| (name.to_string(), FormValue::from(*name)))
+            .collect();
+
+        let answers = draft_answers(&steps, &drafts);
+
+        // "b" is skipped by the branch, and going back to "a" stops the loop
+        assert_eq!(answers.text("a"), Some("a"));
+        assert_eq!(answers.get("b"), None);
+        assert_eq!(answers.text("c"), Some("c"));
+        assert_eq!(answers.get("d"), None);
+    }
+
+    #[test]
+    fn test_wizard_select_frame() {
+        let prefix_map = build_prefix_map();
+        let mut cli_prompt = CliPrompt::new();
+        cli_prompt.set_color_mode(ColorMode::Never);
+        let options = vec![
+            PromptSelectOption::new("web", "Web app"),
+            PromptSelectOption::new("cli", "Command line tool"),
+        ];
+        let context = StepContext {
+            position: 1,
+            len: Some(3),
+            cleared_height: 0,
+        };
+
+        let frame = cli_prompt.wizard_select_frame("Template", &options, 1, &context, true);
+        let bar = prefix_map.get("s_bar").unwrap();
+
+        assert_eq!(
+            frame[2],
+            format!(
+                "{} {} Command line tool",
+                bar,
+                prefix_map.get("s_radio_active").unwrap()
+            )
+        );
+        assert_eq!(
+            frame[3],
+            format!(
+                "{} (2/3) enter to continue, esc to go back, ctrl+c to cancel",
+                bar
+            )
+        );
+    }
+
+    #[test]
+    fn test_prompt_wizard_invalid() {
+        let mut cli_prompt = CliPrompt::new();
+
+        assert!(matches!(
+            cli_prompt.prompt_wizard(vec![]),
+            Err(OptionsVecEmptyError { .. })
+        ));
+        assert!(matches!(
+            cli_prompt.prompt_wizard(vec![
+                WizardStep::field(FormField::text("a", "A")),
+                WizardStep::field(FormField::number("a", "B")),
+            ]),
+            Err(CliPromptError::InvalidFormError { .. })
+        ));
+    }
+}
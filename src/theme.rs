@@ -0,0 +1,318 @@
+//! Symbols and colors used by [`CliPrompt`](crate::CliPrompt) to render its output.
+//!
+//! Every symbol has a unicode glyph and an ASCII fallback which is used
+//! when the terminal doesn't support unicode.
+//! Builtin presets are [`Theme::default`], [`Theme::ascii`], [`Theme::minimal`] and [`Theme::high_contrast`].
+//!
+//! # Example
+//! ```no_run
+//! use cli_prompts_rs::color::{Color, Style};
+//! use cli_prompts_rs::theme::{Symbol, Theme};
+//! use cli_prompts_rs::CliPrompt;
+//!
+//! let mut theme = Theme::high_contrast();
+//! theme.symbols.bar_start = Symbol::new("╭", "+");
+//! theme.colors.question = Style::new().fg(Color::Cyan);
+//!
+//! let mut cli_prompt = CliPrompt::with_theme(theme);
+//! cli_prompt.intro("example app").unwrap();
+//! ```
+use crate::color::{Color, Style};
+use crate::get_symbol;
+
+/// A glyph with its ASCII fallback.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Symbol {
+    pub unicode: String,
+    pub ascii: String,
+}
+
+impl Symbol {
+    pub fn new(unicode: &str, ascii: &str) -> Self {
+        Self {
+            unicode: unicode.to_string(),
+            ascii: ascii.to_string(),
+        }
+    }
+
+    /// Returns the glyph to print, depending on whether the terminal supports unicode.
+    pub fn get(&self, unicode_support: bool) -> String {
+        get_symbol(&self.unicode, &self.ascii, unicode_support)
+    }
+
+    fn to_ascii(&self) -> Self {
+        Self::new(&self.ascii, &self.ascii)
+    }
+}
+
+/// Every symbol printed by [`CliPrompt`](crate::CliPrompt).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Symbols {
+    /// Prefix of [`intro`](crate::CliPrompt::intro)
+    pub bar_start: Symbol,
+    /// Gutter printed in front of options and empty lines
+    pub bar: Symbol,
+    /// Horizontal line of the note box
+    pub bar_h: Symbol,
+    /// Prefix of [`outro`](crate::CliPrompt::outro) and [`cancel`](crate::CliPrompt::cancel)
+    pub bar_end: Symbol,
+    /// Marker of the focused option
+    pub radio_active: Symbol,
+    /// Marker of the other options
+    pub radio_inactive: Symbol,
+    /// Prefix of prompt messages
+    pub step_submit: Symbol,
+    /// Prefix of [`LogType::Info`](crate::LogType::Info) logs
+    pub info: Symbol,
+    /// Prefix of the spinner finish message
+    pub success: Symbol,
+    /// Prefix of [`LogType::Warn`](crate::LogType::Warn) logs
+    pub warn: Symbol,
+    /// Prefix of [`LogType::Error`](crate::LogType::Error) logs
+    pub error: Symbol,
+    pub corner_top_right: Symbol,
+    pub corner_bottom_right: Symbol,
+    pub connect_left: Symbol,
+    /// Checked option of multi select prompts
+    pub checkbox_active: Symbol,
+    /// Unchecked option of multi select prompts
+    pub checkbox_inactive: Symbol,
+    /// Replaces each typed character of [`prompt_password`](crate::CliPrompt::prompt_password)
+    pub password_mask: Symbol,
+    /// Marker of the collapsed nodes of [`prompt_tree_select`](crate::CliPrompt::prompt_tree_select)
+    pub tree_collapsed: Symbol,
+    /// Marker of the expanded nodes of [`prompt_tree_select`](crate::CliPrompt::prompt_tree_select)
+    pub tree_expanded: Symbol,
+    pub spinner_frames: [Symbol; 4],
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Self {
+            bar_start: Symbol::new("┌", "T"),
+            bar: Symbol::new("│", "|"),
+            bar_h: Symbol::new("─", "-"),
+            bar_end: Symbol::new("└", "-"),
+            radio_active: Symbol::new("●", ">"),
+            radio_inactive: Symbol::new("○", " "),
+            step_submit: Symbol::new("◇", "o"),
+            info: Symbol::new("●", "*"),
+            success: Symbol::new("◆", "*"),
+            warn: Symbol::new("▲", "!"),
+            error: Symbol::new("■", "x"),
+            corner_top_right: Symbol::new("╮", "+"),
+            corner_bottom_right: Symbol::new("╯", "+"),
+            connect_left: Symbol::new("├", "+"),
+            checkbox_active: Symbol::new("◼", "[+]"),
+            checkbox_inactive: Symbol::new("◻", "[ ]"),
+            password_mask: Symbol::new("•", "*"),
+            tree_collapsed: Symbol::new("▸", "+"),
+            tree_expanded: Symbol::new("▾", "-"),
+            spinner_frames: [
+                Symbol::new("◒", "."),
+                Symbol::new("◐", "o"),
+                Symbol::new("◓", "O"),
+                Symbol::new("◑", "0"),
+            ],
+        }
+    }
+}
+
+impl Symbols {
+    /// Returns the symbols with every glyph replaced by its ASCII fallback.
+    pub fn to_ascii(&self) -> Self {
+        Self {
+            bar_start: self.bar_start.to_ascii(),
+            bar: self.bar.to_ascii(),
+            bar_h: self.bar_h.to_ascii(),
+            bar_end: self.bar_end.to_ascii(),
+            radio_active: self.radio_active.to_ascii(),
+            radio_inactive: self.radio_inactive.to_ascii(),
+            step_submit: self.step_submit.to_ascii(),
+            info: self.info.to_ascii(),
+            success: self.success.to_ascii(),
+            warn: self.warn.to_ascii(),
+            error: self.error.to_ascii(),
+            corner_top_right: self.corner_top_right.to_ascii(),
+            corner_bottom_right: self.corner_bottom_right.to_ascii(),
+            connect_left: self.connect_left.to_ascii(),
+            checkbox_active: self.checkbox_active.to_ascii(),
+            checkbox_inactive: self.checkbox_inactive.to_ascii(),
+            password_mask: self.password_mask.to_ascii(),
+            tree_collapsed: self.tree_collapsed.to_ascii(),
+            tree_expanded: self.tree_expanded.to_ascii(),
+            spinner_frames: self.spinner_frames.clone().map(|frame| frame.to_ascii()),
+        }
+    }
+}
+
+/// Every color role used by [`CliPrompt`](crate::CliPrompt).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Colors {
+    /// Prefix symbol of prompt messages
+    pub question: Style,
+    /// Marker of the focused option
+    pub active: Style,
+    /// Checked checkbox of multi select prompts
+    pub selected: Style,
+    /// Symbol of [`LogType::Info`](crate::LogType::Info) logs
+    pub info: Style,
+    /// Symbol and message of [`LogType::Warn`](crate::LogType::Warn) logs
+    pub warn: Style,
+    /// Symbol and message of [`LogType::Error`](crate::LogType::Error) logs,
+    /// and mismatching characters of [`prompt_confirm_typed`](crate::CliPrompt::prompt_confirm_typed)
+    pub error: Style,
+    /// Message of [`cancel`](crate::CliPrompt::cancel)
+    pub cancel: Style,
+    /// Spinner frames
+    pub spinner: Style,
+    /// Symbol of the spinner finish message,
+    /// and matching characters of [`prompt_confirm_typed`](crate::CliPrompt::prompt_confirm_typed)
+    pub success: Style,
+    /// Key hints printed below key-driven prompts
+    pub hint: Style,
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Self {
+            question: Style::new().fg(Color::Magenta),
+            active: Style::new().fg(Color::Green),
+            selected: Style::new().fg(Color::Blue),
+            info: Style::new().fg(Color::Blue),
+            warn: Style::new().fg(Color::Yellow),
+            error: Style::new().fg(Color::Red),
+            cancel: Style::new().fg(Color::Red),
+            spinner: Style::new().fg(Color::Magenta),
+            success: Style::new().fg(Color::Green),
+            hint: Style::new().dim(),
+        }
+    }
+}
+
+impl Colors {
+    /// Returns colors that print every role without styling.
+    pub fn plain() -> Self {
+        Self {
+            question: Style::new(),
+            active: Style::new(),
+            selected: Style::new(),
+            info: Style::new(),
+            warn: Style::new(),
+            error: Style::new(),
+            cancel: Style::new(),
+            spinner: Style::new(),
+            success: Style::new(),
+            hint: Style::new(),
+        }
+    }
+}
+
+/// Symbols and colors of [`CliPrompt`](crate::CliPrompt).
+///
+/// Pass it to [`CliPrompt::with_theme`](crate::CliPrompt::with_theme).
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Theme {
+    pub symbols: Symbols,
+    pub colors: Colors,
+}
+
+impl Theme {
+    /// Default symbols and colors with ASCII glyphs only,
+    /// regardless of the terminal unicode support.
+    pub fn ascii() -> Self {
+        Self {
+            symbols: Symbols::default().to_ascii(),
+            colors: Colors::default(),
+        }
+    }
+
+    /// Default symbols without any color.
+    pub fn minimal() -> Self {
+        Self {
+            symbols: Symbols::default(),
+            colors: Colors::plain(),
+        }
+    }
+
+    /// Default symbols with bold and bright colors.
+    pub fn high_contrast() -> Self {
+        Self {
+            symbols: Symbols::default(),
+            colors: Colors {
+                question: Style::new().fg(Color::BrightMagenta).bold(),
+                active: Style::new().fg(Color::BrightGreen).bold(),
+                selected: Style::new().fg(Color::BrightCyan).bold(),
+                info: Style::new().fg(Color::BrightCyan).bold(),
+                warn: Style::new().fg(Color::BrightYellow).bold(),
+                error: Style::new().fg(Color::BrightRed).bold(),
+                cancel: Style::new().fg(Color::BrightRed).bold(),
+                spinner: Style::new().fg(Color::BrightMagenta).bold(),
+                success: Style::new().fg(Color::BrightGreen).bold(),
+                hint: Style::new().fg(Color::BrightWhite),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_get() {
+        let symbol = Symbol::new("●", ">");
+
+        assert_eq!(symbol.get(true), "●");
+        assert_eq!(symbol.get(false), ">");
+    }
+
+    #[test]
+    fn test_ascii_theme() {
+        let theme = Theme::ascii();
+
+        assert_eq!(theme.symbols.bar.get(true), "|");
+        assert_eq!(theme.symbols.checkbox_active.get(true), "[+]");
+        assert_eq!(theme.symbols.spinner_frames[2].get(true), "O");
+        assert_eq!(theme.colors, Colors::default());
+    }
+
+    #[test]
+    fn test_ascii_theme_is_ascii() {
+        let symbols = Theme::ascii().symbols;
+        let glyphs = [
+            &symbols.bar_start,
+            &symbols.bar,
+            &symbols.bar_h,
+            &symbols.bar_end,
+            &symbols.radio_active,
+            &symbols.radio_inactive,
+            &symbols.step_submit,
+            &symbols.info,
+            &symbols.success,
+            &symbols.warn,
+            &symbols.error,
+            &symbols.corner_top_right,
+            &symbols.corner_bottom_right,
+            &symbols.connect_left,
+            &symbols.checkbox_active,
+            &symbols.checkbox_inactive,
+            &symbols.password_mask,
+            &symbols.tree_collapsed,
+            &symbols.tree_expanded,
+        ];
+
+        for symbol in glyphs.iter().copied().chain(&symbols.spinner_frames) {
+            assert!(symbol.get(true).is_ascii(), "{:?} is not ASCII", symbol);
+        }
+    }
+
+    #[test]
+    fn test_minimal_theme() {
+        let theme = Theme::minimal();
+
+        assert_eq!(theme.symbols, Symbols::default());
+        assert_eq!(theme.colors.question, Style::new());
+        assert_eq!(theme.colors.error, Style::new());
+    }
+}